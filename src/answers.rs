@@ -0,0 +1,139 @@
+// Known puzzle answers, read from answers.toml.
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::solution::Part;
+
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+// Answers can be written as TOML integers or strings, and are compared as rendered text.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    fn render(&self) -> String {
+        match self {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(s) => s.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Unreadable(PathBuf, io::Error),
+    Invalid(PathBuf, String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Unreadable(path, e) => {
+                write!(f, "couldn't read {}: {}", path.display(), e)
+            }
+            AnswersError::Invalid(path, e) => write!(f, "invalid {}: {}", path.display(), e),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(u16, u8), DayAnswers>);
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Answers, String> {
+        let years: BTreeMap<String, BTreeMap<String, DayAnswers>> =
+            toml::from_str(contents).map_err(|e| e.to_string())?;
+        let mut answers = BTreeMap::new();
+        for (year, days) in years {
+            let year: u16 = year
+                .parse()
+                .map_err(|_| format!("year \"{}\" isn't a number", year))?;
+            for (day, day_answers) in days {
+                let day: u8 = day
+                    .parse()
+                    .map_err(|_| format!("day \"{}\" in {} isn't a number", day, year))?;
+                answers.insert((year, day), day_answers);
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| AnswersError::Unreadable(path.to_path_buf(), e))?;
+        Answers::parse(&contents).map_err(|e| AnswersError::Invalid(path.to_path_buf(), e))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<String> {
+        let day_answers = self.0.get(&(year, day))?;
+        match part {
+            Part::One => day_answers.part1.as_ref(),
+            Part::Two => day_answers.part2.as_ref(),
+        }
+        .map(Answer::render)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_numbers_and_strings() {
+        let answers = Answers::parse(
+            r#"
+            [2024.7]
+            part1 = 7885693428401
+            part2 = "348360680516005"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            answers.get(2024, 7, Part::One).as_deref(),
+            Some("7885693428401")
+        );
+        assert_eq!(
+            answers.get(2024, 7, Part::Two).as_deref(),
+            Some("348360680516005")
+        );
+    }
+
+    #[test]
+    fn missing_part_and_day() {
+        let answers = Answers::parse("[2024.9]\npart1 = 1928\n").unwrap();
+        assert_eq!(answers.get(2024, 9, Part::Two), None);
+        assert_eq!(answers.get(2024, 10, Part::One), None);
+    }
+
+    #[test]
+    fn reject_non_numeric_day() {
+        assert_eq!(
+            Answers::parse("[2024.seven]\npart1 = 1\n").unwrap_err(),
+            "day \"seven\" in 2024 isn't a number"
+        );
+    }
+
+    #[test]
+    fn repo_answers_file_is_valid() {
+        let answers = Answers::load(&answers_path()).unwrap();
+        assert_eq!(answers.get(2024, 5, Part::Two).as_deref(), Some("6311"));
+    }
+}
@@ -1,167 +1,234 @@
 // https://adventofcode.com/2023/day/14
 //
-// Each column is independent, so the first breakdown for evaluation should be by column.
-// First idea is to walk downward, checking for stop points (either a # or the top edge), and adding the value of O rocks that are at their final position.
-// Walk downward, counting O's until reaching a free space (i). Upon reaching a free space, scan downward until finding either a O or a #.
-//      If a O is found, move it to the free space, add its value to the total count, and start again from (i+1).
-//      If a # is found at position j, start again from (j+1)
+// The board is packed into one bitmask per row, so a whole row of stones moves at once. Tilting north
+// or south settles the rows nearest that edge first and lets each row's stones drop through the rows
+// beyond it, column by column in parallel. Tilting east or west shifts each row's stones a step at a
+// time until none of them have anywhere left to go.
 
 use core::fmt;
-use std::{time::Instant, usize};
 
-pub fn main() {
-    println!("example load: {}", simple_solve(INPUT_1));
-    println!("problem load: {}", simple_solve(INPUT_2));
+use crate::{cycle::Cycle, grid::Grid, parse::ParseError, point::Direction, solution::Solution};
 
-    println!("second half!");
-    println!("example load: {}", solve_complex(INPUT_1)); // this is broken due to bad instructions in part 2
-    println!("problem load: {}", solve_complex(INPUT_2));
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Board;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        process_input(input)
+    }
+
+    fn part_one(&self, board: &Self::Input) -> usize {
+        let mut board = board.clone();
+        board.tilt(Direction::Up);
+        board.load()
+    }
+
+    fn part_two(&self, board: &Self::Input) -> usize {
+        spin_cycle_load(board)
+    }
 }
 
-pub fn simple_solve(input: &str) -> usize {
-    let input = process_input(input);
-    calculate_board_load(&roll_board(&input))
+const SPIN_CYCLES: usize = 1000000000;
+
+// One spin cycle tilts north, west, south, then east.
+const SPIN: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+// The board repeats long before the last spin cycle, so find the loop and read the last state off it.
+fn spin_cycle_load(input: &Board) -> usize {
+    let cycle = Cycle::find(input.clone(), |board| {
+        let mut board = board.clone();
+        board.spin();
+        board
+    });
+    cycle.state_at(SPIN_CYCLES).load()
 }
 
-fn solve_complex(input: &str) -> usize {
-    let input = &process_input(input); // one first roll in the original north orientation.
-    let mut working_board = input.clone();
-    let now = Instant::now();
-
-    let mut board_states = Vec::new();
-    // board_states.push(working_board.clone()); // put in the initial state.
-    for _i in 0..1000000000 {
-        if _i % 1000000 == 0 {
-            println!("iteration: {}", _i);
-            println!("time elapsed: {}", now.elapsed().as_secs()); // Test took about 33 seconds per million cycles, which means to do a billion cycles would be 9-10h.
-        }
-        for _j in 0..4 {
-            // roll, then rotate, so each cycle ends with a rolled eastward board in a north orientation.
-            working_board = rotate_board(&roll_board(&working_board));
-        }
+// The widest board a row's bitmask can hold.
+const MAX_WIDTH: usize = u128::BITS as usize;
+
+fn parse_grid(input: &str) -> Result<Grid<Space>, ParseError> {
+    let grid = Grid::parse_with(input, "O#.", |c| match c {
+        'O' => Space::Stone,
+        '#' => Space::Wall,
+        _ => Space::Empty,
+    })?;
+    if grid.width() > MAX_WIDTH {
+        return Err(ParseError::at(
+            input,
+            input.trim(),
+            format!(
+                "expected at most {} columns, found {}",
+                MAX_WIDTH,
+                grid.width()
+            ),
+        ));
+    }
+    Ok(grid)
+}
 
-        // println!("iteration: {}", _i);
-        // println!("board load: {}", calculate_board_load(&working_board));
-        // print_board(&rotate_board(&working_board));
-
-        // Check for cycles and add current board
-        if board_states.contains(&working_board) {
-            // cycle detected because a duplicate board state has been found. This means we can calculate the billionth board state with a modulus.
-            println!(
-                "cycled detected that is {} iterations long.",
-                board_states.len()
-            );
-            break;
-        }
-        board_states.push(working_board.clone());
-    }
-    println!(
-        "the billionth cycle is iteration: {}",
-        1000000000 % board_states.len()
-    );
-    working_board = board_states[1000000000 % board_states.len()].clone();
-    calculate_board_load(&working_board)
+fn process_input(input: &str) -> Result<Board, ParseError> {
+    Ok(Board::pack(&parse_grid(input)?))
 }
 
-fn calculate_board_load(input: &Board) -> usize {
-    input
-        .0
-        .iter()
-        .fold(0, |load, i| load + calculate_row_load(i))
+// Row 0 is the north edge, and bit x of a row is column x counting from the west edge.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Board {
+    width: usize,
+    height: usize,
+    walls: Vec<u128>,
+    stones: Vec<u128>,
 }
 
-// calculate load on a fully rolled row.
-fn calculate_row_load(input: &Vec<Space>) -> usize {
-    let mut load = 0;
-    for i in 0..input.len() {
-        match input[i] {
-            Space::Stone => {
-                load = load + (input.len() - i);
-            }
-            _ => (),
+impl Board {
+    fn pack(grid: &Grid<Space>) -> Board {
+        let mask = |space: Space| -> Vec<u128> {
+            grid.rows()
+                .map(|row| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|&(_, &s)| s == space)
+                        .fold(0, |bits, (x, _)| bits | 1 << x)
+                })
+                .collect()
+        };
+        Board {
+            width: grid.width(),
+            height: grid.height(),
+            walls: mask(Space::Wall),
+            stones: mask(Space::Stone),
         }
     }
-    load
-}
 
-fn roll_board(input: &Board) -> Board {
-    Board(input.0.iter().map(roll_row).collect())
-}
+    fn space(&self, x: usize, y: usize) -> Space {
+        if self.walls[y] & 1 << x != 0 {
+            Space::Wall
+        } else if self.stones[y] & 1 << x != 0 {
+            Space::Stone
+        } else {
+            Space::Empty
+        }
+    }
 
-fn roll_row(input: &Vec<Space>) -> Vec<Space> {
-    let width = input.len();
-    let mut output: Vec<Space> = vec![Space::Empty; width];
-    let mut cur_destination: usize = 0;
-    for i in 0..width {
-        match input[i] {
-            Space::Stone => {
-                // simulate stone rolling by removing stone from current space, and placing it in destination space.
-                // this will retain stone position if it was already in a fully rolled place.
-                output[i] = Space::Empty;
-                output[cur_destination] = Space::Stone;
-                cur_destination = cur_destination + 1;
-            }
-            Space::Wall => {
-                output[i] = Space::Wall;
-                cur_destination = i + 1;
+    // Each stone is worth the number of rows from it to the south edge, counting its own.
+    fn load(&self) -> usize {
+        self.stones
+            .iter()
+            .enumerate()
+            .map(|(y, row)| row.count_ones() as usize * (self.height - y))
+            .sum()
+    }
+
+    fn spin(&mut self) {
+        for direction in SPIN {
+            self.tilt(direction);
+        }
+    }
+
+    // Rolls every stone as far as it goes in direction, in place.
+    fn tilt(&mut self, direction: Direction) {
+        match direction {
+            Direction::Up => self.tilt_north(),
+            Direction::Down => self.tilt_south(),
+            Direction::Left => self.tilt_west(),
+            Direction::Right => self.tilt_east(),
+            _ => unreachable!("boards only tilt north, south, east or west"),
+        }
+    }
+
+    fn tilt_north(&mut self) {
+        for y in 1..self.height {
+            let mut moving = self.stones[y];
+            for to in (0..y).rev() {
+                // the stones that still have an empty space just north of them.
+                moving &= !(self.stones[to] | self.walls[to]);
+                if moving == 0 {
+                    break;
+                }
+                self.stones[to + 1] &= !moving;
+                self.stones[to] |= moving;
             }
-            Space::Empty => {
-                output[i] = Space::Empty;
+        }
+    }
+
+    fn tilt_south(&mut self) {
+        for y in (0..self.height.saturating_sub(1)).rev() {
+            let mut moving = self.stones[y];
+            for to in y + 1..self.height {
+                moving &= !(self.stones[to] | self.walls[to]);
+                if moving == 0 {
+                    break;
+                }
+                self.stones[to - 1] &= !moving;
+                self.stones[to] |= moving;
             }
         }
     }
-    output
-}
 
-// rotates the input board counter-clockwise.
-fn rotate_board(input: &Board) -> Board {
-    let width = input.0.len();
-    let mut output: Board = Board(vec![vec![Space::Empty; width]; width]);
-    for i in 0..width {
-        for j in 0..width {
-            output.0[j][(width - 1) - i] = input.0[i][j];
+    fn tilt_west(&mut self) {
+        for (stones, walls) in self.stones.iter_mut().zip(&self.walls) {
+            loop {
+                // the stones with an empty space just west of them, which column 0 never has.
+                let moving = *stones & !((*stones | walls) << 1) & !1;
+                if moving == 0 {
+                    break;
+                }
+                *stones = *stones & !moving | moving >> 1;
+            }
         }
     }
-    output
-}
 
-// Reads in input with a north->south row orientation for the 2d vector.
-fn process_input(input: &str) -> Board {
-    let input = input.trim();
-    let width = input.lines().next().unwrap().trim().len();
-    let mut output: Board = Board(vec![vec![Space::Empty; width]; width]);
-    for (i, line) in input.lines().enumerate() {
-        let line = line.trim();
-        if line.len() != width {
-            panic!("line {} doesn't match width.", line.len());
+    fn tilt_east(&mut self) {
+        for (stones, walls) in self.stones.iter_mut().zip(&self.walls) {
+            let last_column = 1 << (self.width - 1);
+            loop {
+                let moving = *stones & !((*stones | walls) >> 1) & !last_column;
+                if moving == 0 {
+                    break;
+                }
+                *stones = *stones & !moving | moving << 1;
+            }
         }
-        for (j, ichar) in line.chars().enumerate() {
-            let space = match ichar {
-                'O' => Space::Stone,
-                '#' => Space::Wall,
-                '.' => Space::Empty,
-                e => panic!("invalid input: {}", e),
-            };
-            output.0[(width - 1) - j][i] = space;
+    }
+
+    // Only the tests turn boards, now that tilts go every way directly.
+    #[cfg(test)]
+    fn rotate_clockwise(&self) -> Board {
+        let mut rotated = Board {
+            width: self.height,
+            height: self.width,
+            walls: vec![0; self.width],
+            stones: vec![0; self.width],
+        };
+        for y in 0..rotated.height {
+            for x in 0..rotated.width {
+                let bit = 1 << x;
+                match self.space(y, self.height - 1 - x) {
+                    Space::Wall => rotated.walls[y] |= bit,
+                    Space::Stone => rotated.stones[y] |= bit,
+                    Space::Empty => {}
+                }
+            }
         }
+        rotated
     }
-    output
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-struct Board(Vec<Vec<Space>>);
-
 impl fmt::Display for Board {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut output = "".to_string();
-        for i in &self.0 {
-            let mut line = "".to_string();
-            for j in i {
-                line = line + &j.to_string();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                self.space(x, y).fmt(f)?;
             }
-            output = output + &line + "\n";
+            writeln!(f)?;
         }
-        f.write_str(&output)
+        Ok(())
     }
 }
 
@@ -185,111 +252,207 @@ impl fmt::Display for Space {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::{example, puzzle};
+    use crate::solution::{solve, Part};
+
+    fn board(input: &str) -> Board {
+        Board::pack(&parse_grid(input).unwrap())
+    }
+
+    // Tilts a single column north, listed from north to south, and weighs it.
+    fn solve_column(column: &str) -> usize {
+        let rows: String = column.chars().map(|c| format!("{}\n", c)).collect();
+        let mut board = board(&rows);
+        board.tilt(Direction::Up);
+        board.load()
+    }
+
+    fn tilted(input: &str, direction: Direction) -> String {
+        let mut board = board(input);
+        board.tilt(direction);
+        board.to_string()
+    }
+
+    #[test]
+    fn reject_unknown_space() {
+        let error = Solver.parse("O.#\n.?.\n#..\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "?"));
+    }
+
+    #[test]
+    fn reject_wide_board() {
+        let row = ".".repeat(MAX_WIDTH + 1);
+        let error = Solver.parse(&row).unwrap_err();
+        assert_eq!(error.message, "expected at most 128 columns, found 129");
+    }
+
+    // 3 rows of 6.
+    const RECTANGLE: &str = "
+        O..#.O
+        .O...#
+        #.O.O.
+    ";
+
+    #[test]
+    fn rectangular_board() {
+        let board = board(RECTANGLE);
+        assert_eq!((board.width, board.height), (6, 3));
+        assert_eq!(board.to_string(), "O..#.O\n.O...#\n#.O.O.\n");
+        assert_eq!(board.load(), 10);
+    }
+
+    #[test]
+    fn rectangular_tilt() {
+        assert_eq!(tilted(RECTANGLE, Direction::Up), "OOO#OO\n.....#\n#.....\n");
+        assert_eq!(solve(&Solver, RECTANGLE, Part::One), 15);
+    }
+
+    #[test]
+    fn rectangular_spin() {
+        let mut board = board(RECTANGLE);
+        board.spin();
+        assert_eq!(board.to_string(), "...#.O\n....O#\n#..OOO\n");
+        assert_eq!(solve(&Solver, RECTANGLE, Part::Two), 9);
+    }
 
-    fn solve_row(input: &Vec<Space>) -> usize {
-        calculate_row_load(&roll_row(&input))
+    #[test]
+    fn rectangular_rotate() {
+        let rotated = board(RECTANGLE).rotate_clockwise();
+        assert_eq!((rotated.width, rotated.height), (3, 6));
+        assert_eq!(rotated.to_string(), "#.O\n.O.\nO..\n..#\nO..\n.#O\n");
     }
 
     #[test]
     fn input_1_simple() {
-        assert_eq!(simple_solve(INPUT_1), 136);
+        assert_eq!(solve(&Solver, &example(2023, 14, 1), Part::One), 136);
     }
 
     #[test]
     fn input_2_simple() {
-        assert_eq!(simple_solve(INPUT_2), 106378);
+        assert_eq!(solve(&Solver, &puzzle(2023, 14), Part::One), 106378);
+    }
+
+    #[test]
+    fn input_1_complex() {
+        assert_eq!(solve(&Solver, &example(2023, 14, 1), Part::Two), 64);
     }
 
     #[test]
     fn input_2_complex() {
-        assert_eq!(solve_complex(INPUT_2), 90795);
+        assert_eq!(solve(&Solver, &puzzle(2023, 14), Part::Two), 90795);
     }
 
     #[test]
     fn single_column_count_static_stone() {
-        let input1 = [Space::Stone, Space::Empty].to_vec();
-        assert_eq!(solve_row(&input1), 2);
-
-        let input2 = [Space::Stone, Space::Stone].to_vec();
-        assert_eq!(solve_row(&input2), 3);
+        assert_eq!(solve_column("O."), 2);
+        assert_eq!(solve_column("OO"), 3);
     }
 
     #[test]
     fn single_column_stone_should_fall() {
-        let input1 = [Space::Empty, Space::Stone, Space::Empty].to_vec();
-        assert_eq!(solve_row(&input1), 3);
-
-        let input2 = [Space::Empty, Space::Stone, Space::Stone].to_vec();
-        assert_eq!(solve_row(&input2), 5);
+        assert_eq!(solve_column(".O."), 3);
+        assert_eq!(solve_column(".OO"), 5);
     }
 
     #[test]
     fn single_column_respect_walls() {
-        let input = [
-            Space::Empty,
-            Space::Stone,
-            Space::Wall,
-            Space::Empty,
-            Space::Stone,
+        assert_eq!(solve_column(".O#.O"), 7);
+    }
+
+    #[test]
+    fn tilt_each_way() {
+        let input = "
+            .O.O
+            O#..
+            ..O.
+            O..#
+        ";
+        assert_eq!(tilted(input, Direction::Up), "OOOO\nO#..\n....\n...#\n");
+        assert_eq!(tilted(input, Direction::Down), ".O..\n.#..\nO..O\nO.O#\n");
+        assert_eq!(tilted(input, Direction::Left), "OO..\nO#..\nO...\nO..#\n");
+        assert_eq!(tilted(input, Direction::Right), "..OO\nO#..\n...O\n..O#\n");
+    }
+
+    #[test]
+    fn tilts_match_rotating_north() {
+        let input = board(&example(2023, 14, 1));
+        for (turns, direction) in [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
         ]
-        .to_vec();
-        assert_eq!(solve_row(&input), 7);
+        .into_iter()
+        .enumerate()
+        {
+            // turn the board so direction faces north, tilt, and turn it back.
+            let mut rotated = input.clone();
+            for _ in 0..turns {
+                rotated = rotated.rotate_clockwise();
+            }
+            rotated.tilt(Direction::Up);
+            for _ in 0..(4 - turns) % 4 {
+                rotated = rotated.rotate_clockwise();
+            }
+            let mut direct = input.clone();
+            direct.tilt(direction);
+            assert_eq!(direct, rotated, "tilting {:?}", direction);
+        }
     }
 
     #[test]
-    fn rotate_once() {
-        let input = Board(
-            [[Space::Empty, Space::Stone], [Space::Empty, Space::Empty]]
-                .map(|x| x.to_vec())
-                .to_vec(),
-        );
-        let expected = Board(
-            [[Space::Empty, Space::Empty], [Space::Empty, Space::Stone]]
-                .map(|x| x.to_vec())
-                .to_vec(),
+    fn spin_once() {
+        let mut board = board(&example(2023, 14, 1));
+        board.spin();
+        assert_eq!(
+            board.to_string(),
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+"
         );
-        assert_eq!(rotate_board(&input), expected);
+    }
+
+    #[test]
+    fn rotate_once() {
+        assert_eq!(board(".O\n..").rotate_clockwise(), board("..\n.O"));
     }
 
     #[test]
     fn rotate_twice() {
-        let input = Board(
-            [[Space::Empty, Space::Stone], [Space::Empty, Space::Empty]]
-                .map(|x| x.to_vec())
-                .to_vec(),
-        );
-        let expected = Board(
-            [[Space::Empty, Space::Empty], [Space::Stone, Space::Empty]]
-                .map(|x| x.to_vec())
-                .to_vec(),
+        assert_eq!(
+            board(".O\n..").rotate_clockwise().rotate_clockwise(),
+            board("..\nO.")
         );
-        assert_eq!(rotate_board(&rotate_board(&input)), expected);
     }
 
     #[test]
     fn rotate_thrice() {
-        let input = Board(
-            [[Space::Empty, Space::Stone], [Space::Empty, Space::Empty]]
-                .map(|x| x.to_vec())
-                .to_vec(),
-        );
-        let expected = Board(
-            [[Space::Stone, Space::Empty], [Space::Empty, Space::Empty]]
-                .map(|x| x.to_vec())
-                .to_vec(),
+        assert_eq!(
+            board(".O\n..")
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            board("O.\n..")
         );
-        assert_eq!(rotate_board(&rotate_board(&rotate_board(&input))), expected);
     }
 
     #[test]
     fn rotate_fource() {
-        let input = Board(
-            [[Space::Empty, Space::Stone], [Space::Empty, Space::Empty]]
-                .map(|x| x.to_vec())
-                .to_vec(),
-        );
+        let input = board(".O\n..");
         assert_eq!(
-            rotate_board(&rotate_board(&rotate_board(&rotate_board(&input.clone())))),
+            input
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
             input
         );
     }
@@ -304,122 +467,7 @@ mod tests {
 .#...
         "#;
 
-        assert_eq!(solve_complex(input), 5);
+        // loops every 2 cycles after the first, so the start offset matters.
+        assert_eq!(solve(&Solver, input, Part::Two), 4);
     }
 }
-
-const INPUT_1: &str = r#"
-O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....
-"#;
-
-const INPUT_2: &str = r#"
-O....##.##.....OO...O.O......#.OO.#.#....#.#..#..##....###..O.....O#..#.#.......O.#.##...O#OO.O....O
-.#..OOO..#.#..O..O...O.#.......O..O....##..O##O..#..#.......O.O.#....O...O#.O#OOO...O...#O.#......O.
-..#O...#O.......##.O.O..O#..OO..O...#.O...O.#...#.OOO.OO.O......#.....#.O..##.O.#.#...O.O#..#..#O.#.
-O####.O.#.O...#..#...O.......#....#..O....OO#...O..##...#..##..O..####..OO.O..O.#OO..O.O..O##O.....#
-OO..O.##..#.O##O.....#..#O#.O...#.......#O#...#.O....#..O.O.....O#.OO....O......O#O.#..O..OO.O.O....
-..#O.O...O...O..O.OO.O#OO...#......#.....O.#.....#.....O##.#......#O..OO.......#..#..O...#.......O.O
-.O...#.......OO..O.....#....#.#.O.........#....#......#.OO...O.........O.O.O....O..#..#O..##...#.O..
-O#......#.O....#.O.#....#....O.......#O.O#O..O..#...#.O.#..O.OO...O..O....#O#OOOO................#..
-O..O.#..#........O......O..O..#..O.#.O...#O...O##..O.....#OO.#..#...........##.#...O##.O...#..#..O..
-..O.....OO.O#..#O#.....#......OOOO#..#.O.#.#..O..O.....O.O.#....#O...O...O.OO......#O#.#.##......O.O
-.#.#............OO...O.OOO#O..#.#O#.O.#O#.O.O.....#.O..........#.....O#..O...O#.....O......##O..#OO.
-.O#O....O#..#....OO.....#...O...O..O.O..O..##....#.#.....O....O..#O..........#O...#O##.#....O..##O..
-....OOO.O..O#..#.OO.....O#..O.O#...#.....##OO.....OO...##O.....O......O#..O##...#...##.O..O....#.#..
-.#......O.#..O...........O...#..O#.O.#O#..OO.........O.O...O##.........##.O#.........#.O......#...#.
-O.#...##..#..........#OO........O#..##..##.#O#O..O..#O..O....O.OO....O...OOO...O.....#.OO#..#.#.....
-.##.#O.##..#....O.##O..O.#..O...#...O....O#....O...O.#O#...........##O..O..#.O....O........O.#...OOO
-.O#......OOOO...#..O....#..#.O#.#.##.##O#.#.#..O....O#..........O..#..O...#.....O........#....O.#..#
-.....O......#..#...O....OO...O#OO...OO.O....#..O.O.....O#OO.#....#.#.#......O......OO........#.#.##.
-......O......O...O##.#O........O.......#.##.O......O.#.#.#.....#.O....#..#O...O##..#..#.##...O#O.O.O
-..OO.O...#..OO#.OO..O......##......O.#...#....O.OO..O...#.......#O##..OO....OO.O..#.O...#O..#.#O....
-O...O.#.O..#.OOO...........O..#.#....OO..OO.O....O#O#O#..OOOO....OOO.OOO#....#....O#..O#.O.#....O...
-.......O.....O..O.#...O.#...#O.O..#......#...O#OOOO#..OOO..#..O....#...O..O..O.#....O.O.O.O...#O....
-#...O.#.........OO...#......OO....O.##OOO#O.......#.O..#..O.#O#.O...O.OOO.O##OO.#.O.O..#.....O...O#.
-O.OOOOO##O#.......O#.....O...O.#...O..OOO.....##...O...O....O.#....##O....#.#OO..#..#O..OO#OO#......
-...........O...#.O....O##O..O.O....#.#O...O..OO.......#..#.#...#.........##..#.O...#.O...OOO..O.#OO.
-..O##....#....O.#.O#O##.OOOOO..O.#..#...O..#...#.##.#O.....#..O..O.O#...#.O..#....#O.#........O#...#
-#...O.......#.O....O......OO#.##..##O..O..O....O.O##.OOO......#......#...OO..O.O..........OO.O.O..O.
-........OO.O.#.#.O...............O......O...O....#........O..O....O..O.....O.O..O.O.O#O#.O..##......
-O..O.......#O..O..O.O....O......O##.#...O##..#...O.O#.#.O..O.O#.#.....O...O#.#.#.....O.....#.#......
-.#......O..O..#O....O...O...O.......#..#..#.O...##..#......O.O.OOOO...##..#.OO......#O.O.O#...O.##O#
-..OOO...#.O.O..#.#..O....##.O....#.O..OO#.O.......O..##O##.#OO.....#.....#.....O.#O.OO#....#O..OO.O.
-..O#OO#.#....O.#..O.#OO#.#...O.#.#.OO....O..O.#...#..O..OO.#.O#..##.#......#O.#.#..O..O...OO#..OOO..
-#.#OO.....O...O......O............O...###..O.#.OO...O...#...O.....##OO#.......#...#.OO.#...#....#...
-....O....#.#OO##OO...OO...#..#.#.....O.....O..O....#......O....O#....OO.#....O....O...#.O..#OOO.OOOO
-.#..O.O.O.O.#.O##O..#..O...........O..........OO#.#..O...#O....#OO...O.#.....O.OOOO....O.......#O.#.
-...O##....O.....#O..#.OO........#...O..#O#..OO#........###.O..O......O..#...##.#..#..#O.....O.#..#.#
-.#..O.....O...OO#OO..#......O.#....#..#.#.....O.....OO.O...#.....###...O....O.......OO#..#....O.#..O
-O...#.O..O....#.O....#...#.O....#.O..##.O.O#.O#O.O.....O.#OO#.#O......#.O..#.#..#........O.O...O..O#
-......O.OOOO.O....O.#.##.O...#O....OOO...O..O###..OOO.........OO.O..O...OO.##..#.#..##.O.#...#....#.
-.#O...#.##.O....#.#...##O....O#O.O.#.O.#.O...#...O.#.O#O....O.#..###.O.#OOOO.O#OO.....OOO#.....##..O
-..O##O.#.#..O...#O..O.##.#O..O.#..OO.O..........O.....O#.#.OOO........#O.O#.#..O..OOO....#..O.O.#..O
-OO.#O....OO#........O.O.#..#..O#.O.OO#O...O.#.O........O##.OO..O..#..O........O#.#O.............O...
-................O#...........O........O#.O..O#..#O.O.O..#.....#.#O..O.O.#.....#.O.....#...#.#O..#..#
-#....#...#....#.#O......##O.....#.#........#.O....OO#..OO.#....OO..#....O....OO..#..##......#O.O.#..
-O####O.##....####O..#.#.OO.O..#O#O...O.OOO...#O##..##......O...O..OO..#.O#O...O.#O........O.....#..#
-...OOO.O.....O..#.O.....O.OO...OOO#O.........O...O..#...#OO.....#..O....#....#.......##.....#...#.O#
-#.....O#..O#.#.....#..O......#..O.......O##.....##.##O..OO##.OO#..##......O......O....O....O#....O..
-O....OO......O.....O...O#.O..#.#...#....#..#....OO.O##O..##O..O#.O.#.OO#.OOO....#.OO..#O..O..#..##..
-O.O#.#OO..O..O...OO......OO.......#.O#O.O#....#.O#O..O#.#...O#..O...O....O.#OO#.#..O.##..O.....#..OO
-.##.O...O.O..O....OO...O#....OO..#.....#.....OO##.#.O.#......#.O...O..O...##..#O.....#.O....#O......
-.OO#..O.O.....#.O.OO.##..#.....O......O..OO...O.O.O##OO.#O...O..O..O#...OO..O.....#.............O.OO
-..O....#O..##.O..#...O#..#.OO....##.O.....#..##.O...#.O...O.#O.OO..#O...O##.O.......#.O.#.O..#O.....
-.##..........O..#.#.........O....O..O#..##.#..#..O#OO..#..#O...O#O.#..#.#.O.OO......O###.....O.O....
-...#O..O..O#..O#..O#...OO.O.O.#.OO..#.#..#.#.......O.O..#OO#..O....O...#O#..#O....O.#O.#..#.#..O...#
-OO###....OO..#.##OO......#..O###..O.....O.O.#...OO..#O....OO..#O..#.#..OO.#.....O......OOO........OO
-.O....#..#.O.O........O..#....O...O...O#.....OOOO.####.O..#.##.##O##..O.OO...O.OO..#O...O..O.O.#.O..
-##O#...#...O.O#..O..O.#.OOO..O..##....#....##.O.#.O....O.#....O##...OO..O..#..O.O.#.....#OOO.O..O...
-.O.....#....O#.O......#O...#.#.#...O.#..O.O..#...O.#O..O..O......#...........O##..O#O.#.#.#.........
-OO.....O..#.O#.....##O..##....OO....O..O.O..#..O.O........O...#...O....O#O.O..#.O..##.#...#.....###.
-.#..........O.....#O#O....O.OO.#..#...#..O.#...O.#.#.O#O....#O.O..#.....##O#.#.O..#O....O.##...#.O.O
-.O....O....O##.O...O....O....O....O..O............#O...#.#...OOO....O#.....O#...##.......O.#........
-.#.......OOO#............OO..........O.#...O..OO....#..O.#...##.O#....O..O.#.O#O##.O.O...#.#O#.O.O..
-.#..#.#...........O.#..##..OO#.O..O..O.#OO##O.O..OOO....OO..#OO..#..###..O....##...........#O.###.O.
-O....O..O...#........O.#OOOOO#.O.....O.#...O.OOO#.#......#.OO#.#.O..#..#O....##....#.###...#.###..#.
-..O...O#.O.....#.OOO.O....O.......#O...O..#.#......#.#..O......OOO.#..O.#O..O..O#..#..#...#....O.#.O
-O....O...OO.....#.....O#..#..##...O..O.....##....#O.....##.#..O#.O...#O.....O##.##O.....#O..O..O...#
-O.##.OO.O.O.O...O..O..O...O.O...O.#..O...###....O..O##O#....OO.......#....#OO....#.#.......O....OO#O
-.O......OO.....##...##.#..#..........##O..#.O#.#....#...#.OO..O.....#...O.......##.OO##....O..OO....
-O....O#.....#.OO..O#..OO.OO#..O...O.O.OO.O.O.#...O.#OO..O#.....#.#.....#..O....O...........O#.#.#...
-#..#.#..#.....#O.O.#####........O..#......#O......##.........#....#.#O.#.O...O.#..O.O..#O.#O..OO..#O
-...O..O...O.OOOO.O#O###..O...#..#.......O#......O..O#O.OO.#..#.O.O...O....O.....###...#......##....O
-..#.#O........O#O..##OOO....O##.#..O...#.O.O##..#OO.....#OO.#......O...#...#.O.#.O#.....O...O...##.#
-O.....OO....OO#..O.....O....O..#.#.......OO#O........##.O..O#O.#.##.O..O.#.#.#......#O.##....O.#OO.O
-..O..OO..O..O#....O...#....OO#O.......O....O..##O.O.O#..#O#....#..O.....#O.#.O#....##....O......#...
-......#O#..O#..OO#...#......#.#.......O#..#.O#.O.O.OO...#..#O..##..#O..O...#..#.O....##O.O##O..O.#.O
-..#O...#...O.........OO........O.O.O#......OO.#.....O.O..O#.#O#.....####.OO....#O#OO..#O.O..O##O...#
-.#.O..#...O.#.O.OO###.#.OO#..O.#OO.#.#.#....O...#O#..OOOO#.......#.O...OO.....O#.#...##.............
-.#.#.OO....O.........O..##O.#.O........O.#O.O##...O....O.O.#OOO....OO.O..O.OO...O..#OO...#......#..#
-......O.....O..O...#...#......O..#O#...#...#...O...OO.........O..O.O.OOO.O.#.O.O....OO.O.#O..O...O..
-........#....OO......#.....#O#...#..O.O..#..O..O.##O.O..........#...#.O...O#.O..........OOOO.O....O.
-O..#.O.O....OO...#.O.......O....#.O#...O#.......OO..#O###O#O.O.O.#...#...#O#.O..O...OOO.OOO.#.#.....
-.#.OO......O#.#...OO.#..O....O#O.O#..........O......#.#.O....O...O.#O.#OO........#..#...##..#....OOO
-O.....#....O......O......OO#O#.##.##OO..O............O.OO...O#.....#...##...#...OO.OO.......O.....O.
-.....OO.O......O.........O.#.O.....#.#O......O..O..#.#.##.........O##OO....O..OO...#........#...##..
-#..###......O.....O#OO...#..#O....O.OO..O...........#..#.#...O##O...O.O....#.OO..#.#.OO....#..#O.O.#
-.........O#.O#...##.#.#...O#.O.........O.#..OO........O.O.....#O....O#.#..O.O..#....#.....#.......O.
-...#O...O.#...OOOO.#O..O.O#.....#.#.....O..#.....O.#.O......O.##O.O..O........#.........#O..O#..#...
-#.#.#O#.O..#O.#O..#.##..O#.O..#.O.OOO...........O..O.O.#O..#....O....#O.##O.##O......O..OOO...#O...O
-O.#O##.....#.O.O.OO.O..O....O...#O...........O.##O##...OO...##.#.#.#.#.O..O...#......O...O.#..#.#.O.
-.OO....O.....#O.O........O#.......O...O.O##O..#....O.#O.#.O.....##..#...#.OO.O.O..##...#O.OOO#O..#.#
-##O#OOO.##...#.O...#....#...O#.....O....#.OOO.O.##..OO.OO...O.O.O..O.O..#.#.#.##...O...#..#.#.O.....
-..O#..#OOO#....O...#.O.#...#....#...#....O..........O.O#.....O.....O.OO...O.#.......O.#...O.........
-#..#.#.O#.OO.O...O..#....#.O..OO.....#..O...O.#.#...OO....O#..O....###...###..O.O.O.O.O..OOO.....OO.
-#..#..#.O..#..O.O..O#....##O.OO.O#OO.......O........#..O..#.O..#.#OOOO...O..#OOO....#..#..OOO......O
-.#O.#.#.#...#OOO.OO..#O.##.....O..#O.##.O.O.O..OOO#....O.##......OOOOOO...O..O......#...#.O...O.#...
-#O..O.O###...##O...O........##.O#....#O..O.O.O#.#OO##O.O.O#.#.##O.OO........O.........###O.O.#O#OO#O
-O..#O......#.#O...O#...#####..#O#...O.##OO......O.....O.......O.O.#..O.O...........#OO..#..OOO....#.
-....O.O#..#O#.#....#O.O##..#...O...#O.#.#.##O...OO....O..#..OO#........#O.........#O..O.#..OO.......
-#..##.#.O#O..#O..OO...#.....#..O#..O....O#.....OO.O......O..#....O.##O#O...O....#..O.......O....O.##
-#....#O..#O.O..#....OO#.#OO...O..O.......O..#......#.......#.O.#..OO..O........OO.......#O.O.#O....O
-"#;
@@ -1,72 +1,70 @@
 // https://adventofcode.com/2024/day/4
-use itertools::{self, Itertools};
+use crate::{
+    grid::Grid,
+    parse::ParseError,
+    search::{self, Shape},
+    solution::Solution,
+};
 
-// no (0,0)
-const SLOPES: [(i32, i32); 8] = [
-    (-1, -1),
-    (-1, 0),
-    (-1, 1),
-    (0, -1),
-    (0, 1),
-    (1, -1),
-    (1, 0),
-    (1, 1),
-];
+const WORD: &str = "XMAS";
 
-const PATTERN: [char; 4] = ['X', 'M', 'A', 'S'];
+// Two MAS crossing at the A, in any orientation.
+const CROSS: &str = "
+    M.S
+    .A.
+    M.S
+";
 
-type Grid = Vec<Vec<char>>;
+fn process_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, "XMAS")
+}
 
-// fn process_input(input: &str) -> Grid {
+pub struct Solver;
 
-// }
+impl Solution for Solver {
+    type Input = Grid<char>;
+    type Answer = i32;
 
-// First pass finds all X's. Second pass on all X's finds all directions with M, and continues down those directions for a and s.
-fn solve_simple(input: &str) -> i32 {
-    -1
-}
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        process_input(input)
+    }
 
-// fn solve_complex(input: &str) -> i32 {}
+    fn part_one(&self, grid: &Self::Input) -> i32 {
+        search::find_words(grid, &[WORD]).len().try_into().unwrap()
+    }
 
-pub fn main() {}
+    fn part_two(&self, grid: &Self::Input) -> i32 {
+        let shapes = Shape::new(CROSS).rotations();
+        search::find_shapes(grid, &shapes).len().try_into().unwrap()
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::example;
+    use crate::solution::{solve, Part};
 
-    // Puzzle tests
-    // #[test]
-    // fn solve_simple_input_1() {
-    //     assert_eq!(solve_simple(INPUT_1), 161);
-    // }
-
-    // #[test]
-    // fn solve_simple_input_2() {
-    //     assert_eq!(solve_simple(INPUT_2), 159833790);
-    // }
+    #[test]
+    fn reject_unknown_letter() {
+        let error = Solver
+            .parse(
+                "XMAS
+XMAZ
+",
+            )
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
 
-    // #[test]
-    // fn solve_complex_input_3() {
-    //     assert_eq!(solve_complex(INPUT_3), 48);
-    // }
+    // Puzzle tests
+    #[test]
+    fn solve_simple_input_1() {
+        assert_eq!(solve(&Solver, &example(2024, 4, 1), Part::One), 18);
+    }
 
-    // #[test]
-    // fn solve_complex_input_2() {
-    //     assert_eq!(solve_complex(INPUT_2), 89349241);
-    // }
+    #[test]
+    fn solve_complex_input_1() {
+        assert_eq!(solve(&Solver, &example(2024, 4, 1), Part::Two), 9);
+    }
 }
-
-const INPUT_1: &str = r#"
-MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX
-"#;
-
-const INPUT_2: &str = r#""#;
@@ -2,238 +2,246 @@
 
 use std::collections::HashSet;
 
-use itertools::Itertools;
+use itertools::Either;
 
-type Grid = Vec<Vec<char>>; // [row][column]
-type X = isize; // where leftmost char is x = 0.
-type Y = isize; // where top row is y = 0.
-#[derive(Hash, Clone, Copy, Debug, PartialEq, Eq)]
-struct Coord(X, Y);
+use crate::{
+    grid::Grid,
+    parallel,
+    parse::{self, ParseError},
+    point::{Direction, Point},
+    solution::Solution,
+};
 
 // Grid + walk direction, that outputs (x,y) coordinates until an obstacle is reached, where it outputs None.
 struct Griderator<'a> {
-    grid: &'a Grid,
-    position: Coord,
-    direction_index: usize,
-}
-
-// The walking directions a guard. Up, right, down, left
-const DIRECTION: [Coord; 4] = [Coord(0, -1), Coord(1, 0), Coord(0, 1), Coord(-1, 0)];
-
-// returns the Coord as usize if valid, else None.
-fn try_usize(coord: Coord) -> Option<(usize, usize)> {
-    let Ok(row): Result<usize, _> = coord.1.try_into() else {
-        return None;
-    };
-    let Ok(column): Result<usize, _> = coord.0.try_into() else {
-        return None;
-    };
-    Some((column, row))
+    grid: &'a Grid<char>,
+    position: Point,
+    direction: Direction,
 }
 
 // whether the guard will still be on map next step.
-fn on_map(coord: Coord, grid: &Grid, direction_index: usize) -> bool {
-    match try_usize(Coord(
-        coord.0 + DIRECTION[direction_index].0,
-        coord.1 + DIRECTION[direction_index].1,
-    )) {
-        Some((column, row)) => row < grid.len() && column < grid[row].len(),
-        None => false,
-    }
+fn on_map(coord: Point, grid: &Grid<char>, direction: Direction) -> bool {
+    grid.contains(coord + direction)
 }
 
 impl<'a> Iterator for Griderator<'a> {
-    type Item = Coord;
+    type Item = Point;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let Some((column, row)) = try_usize(self.position) else {
-            return None;
-        };
-        if row >= self.grid.len() || column >= self.grid[row].len() || self.grid[row][column] == '#'
-        {
+        if *self.grid.get(self.position)? == '#' {
             return None;
         }
 
         let output = self.position;
-        self.position = Coord(
-            self.position.0 + DIRECTION[self.direction_index].0,
-            self.position.1 + DIRECTION[self.direction_index].1,
-        );
-        return Some(output);
+        self.position += self.direction;
+        Some(output)
     }
 }
 
-fn process_input(input: &str) -> Grid {
-    input
-        .trim()
-        .lines()
-        .map(|line| line.trim().chars().collect())
-        .collect()
+fn process_input(input: &str) -> Result<Grid<char>, ParseError> {
+    let grid = Grid::parse(input, ".#^")?;
+    if grid.position(|&c| c == '^').is_none() {
+        return Err(ParseError::at(
+            input,
+            parse::end(input),
+            "expected a guard (^) on the map",
+        ));
+    }
+    Ok(grid)
 }
 
-fn find_guard(grid: &Grid) -> Coord {
-    for (y, row) in grid.iter().enumerate() {
-        for (x, &c) in row.iter().enumerate() {
-            if c == '^' {
-                return Coord(x.try_into().unwrap(), y.try_into().unwrap());
-            }
-        }
-    }
-    panic!("no guard found");
+fn find_guard(grid: &Grid<char>) -> Point {
+    grid.position(|&c| c == '^').expect("no guard found")
 }
 
-fn solve_simple(input: &str) -> i32 {
-    let grid = process_input(input);
-    let mut cur_pos = find_guard(&grid);
-    let mut direction_index = 0;
+// Walks the guard off the map and counts every space it passes through.
+fn count_visited(grid: &Grid<char>) -> i32 {
+    let mut cur_pos = find_guard(grid);
+    let mut direction = Direction::Up;
     let mut traversed_spaces = grid.clone();
 
     // let mut turn_count = 0;
 
     loop {
         // println!("walk cycle: {:?}", turn_count);
-        // println!("direction: {:?}", direction_index);
+        // println!("direction: {:?}", direction);
         for coord in (Griderator {
-            grid: &grid,
+            grid,
             position: cur_pos,
-            direction_index,
+            direction,
         }) {
-            let Some((column, row)) = try_usize(coord) else {
-                panic!("invalid coord");
-            };
             cur_pos = coord;
-            traversed_spaces[row][column] = 'X';
+            *traversed_spaces.get_mut(coord).unwrap() = 'X';
         }
         // turn_count += 1;
         // check if still on map before rotating.
-        if !on_map(cur_pos, &grid, direction_index) {
+        if !on_map(cur_pos, grid, direction) {
             break;
         }
-        direction_index = (direction_index + 1) % 4;
+        direction = direction.turn_right();
     }
-    traversed_spaces.iter().flatten().fold(0, |sum, c| match c {
+    traversed_spaces.iter().fold(0, |sum, (_, c)| match c {
         'X' => sum + 1,
         _ => sum,
     })
 }
 
-// len 4 array that records what direction it was passed in, based on DIRECTION index.
-type DirectionalSpace = [bool; 4];
-type LoopGrid = Vec<Vec<DirectionalSpace>>;
-
-fn detect_loop(grid: &Grid) -> bool {
-    let mut cur_pos = find_guard(grid);
-    let mut direction_index = 0;
-    let mut traversed_spaces: LoopGrid = vec![vec![[false; 4]; grid.len()]; grid.len()];
+// Where the guard ends up from each cell walking in each direction, indexed by `quarter`: the cell
+// just before the next obstacle, or None if it walks off the map first.
+struct JumpTable(Grid<[Option<Point>; 4]>);
 
-    // let mut turn_count = 0;
+// Index of an orthogonal direction, counting quarter turns clockwise from Up.
+fn quarter(direction: Direction) -> usize {
+    direction as usize / 2
+}
 
-    loop {
-        // println!("walk cycle: {:?}", turn_count);
-        // println!("direction: {:?}", direction_index);
-        for coord in (Griderator {
-            grid: &grid,
-            position: cur_pos,
-            direction_index,
-        }) {
-            let Some((column, row)) = try_usize(coord) else {
-                panic!("invalid coord");
+impl JumpTable {
+    fn new(grid: &Grid<char>) -> JumpTable {
+        let mut table = Grid::new(grid.width(), grid.height(), [None; 4]);
+        let cells: Vec<Point> = grid.iter().map(|(position, _)| position).collect();
+        for direction in Direction::ORTHOGONAL {
+            // visit cells so that the one ahead in this direction is always filled in first.
+            let ordered = match direction {
+                Direction::Up | Direction::Left => Either::Left(cells.iter()),
+                _ => Either::Right(cells.iter().rev()),
             };
-            // if the current space has been visited with the current direction index, a loop has occurred.
-            if traversed_spaces[row][column][direction_index] == true {
-                return true;
+            for &position in ordered {
+                let ahead = position + direction;
+                let stop = match grid.get(ahead) {
+                    None => None,
+                    Some('#') => Some(position),
+                    Some(_) => table.get(ahead).unwrap()[quarter(direction)],
+                };
+                table.get_mut(position).unwrap()[quarter(direction)] = stop;
             }
-            traversed_spaces[row][column][direction_index] = true;
-            cur_pos = coord;
         }
-        // turn_count += 1;
-        // check if still on map before rotating.
-        if !on_map(cur_pos, &grid, direction_index) {
-            break;
+        JumpTable(table)
+    }
+
+    // Like the table lookup, but with one extra obstacle on the map.
+    fn next_stop(&self, position: Point, direction: Direction, obstacle: Point) -> Option<Point> {
+        let stop = self.0.get(position).unwrap()[quarter(direction)];
+        let step = direction.offset();
+        // how many steps ahead a point is, if it's on the guard's line of sight at all.
+        let distance = |point: Point| {
+            let delta = point - position;
+            let steps = delta.x * step.x + delta.y * step.y;
+            (steps > 0 && step * steps == delta).then_some(steps)
+        };
+        match (distance(obstacle), stop) {
+            (Some(to_obstacle), Some(stop)) if to_obstacle > distance(stop).unwrap_or(0) => {
+                Some(stop)
+            }
+            (Some(_), _) => Some(obstacle - step),
+            (None, stop) => stop,
+        }
+    }
+}
+
+// Whether the guard, standing at position facing direction, gets stuck in a loop once obstacle
+// is added. Only the corners the guard turns at are tracked, since a loop has to repeat one.
+fn loops_with(
+    jumps: &JumpTable,
+    obstacle: Point,
+    mut position: Point,
+    mut direction: Direction,
+) -> bool {
+    let mut turns = HashSet::new();
+    while let Some(stop) = jumps.next_stop(position, direction, obstacle) {
+        if !turns.insert((stop, direction)) {
+            return true;
         }
-        direction_index = (direction_index + 1) % 4;
+        position = stop;
+        direction = direction.turn_right();
     }
     false
 }
 
-fn solve_complex(input: &str) -> i32 {
-    // BRAINSTORM encode into the traversed spaces vector a walking direction. If passing a space that was previously where the direction is to the right of the current walk direction, place a O (for the obstacle) in front of the current position, and continue traversing
-    // direction can be encoded as just the direction_index, and "rightness" can be checked by if the space passed has a direction of (cur_dir + 1) % 4.
-    //
-    // Most brute force and slow solution: test placing an obstacle in front of every space visited and see if that forms a cycle.
-    // In this solution, a cycle is detected if a space is entered with the same direction it was entered previously, and you can short circuit. Can also skip if the potential obstacle location already has an obstacle there.
-    // when you are testing for a cycle, start from the initial guard position for simplicity.
-    //
-    // Some more complicated cases:
-    // 1. It's possible to traverse the same spaces in the opposite direction without looping, so one direction value is insufficient. This may also cause some complications.
-    // I think it is sufficient for now that it is INVALID to place an obstacle on any space that was already traversed. Because placing that where it would have obstructed previous movement makes the current position in the path invalid.
-    // A possible alternative to this is to cast a line out from a potential O location
-    // 2. if there is an unobstructed path to a rightward previously traversed line of spaces, that can also make a loop. Maybe instead of all spaces, instead cast a line to the right after every step, and if encountering an obstacle already
-    // encountered with the current direction, then place an obstacle in front. This would make the algorithm be checking obstacles as nodes, instead of spaces as paths.
-    // IDEA combine naive solution with complicated case 2.
-    // step 1: do the regular path, recording which direction(s) a space is traversed from. create a tentative obstacle anywhere that a line cast to the right finds another line
-    let grid = process_input(input);
-    let mut cur_pos = find_guard(&grid);
-    let mut direction_index = 0;
-    let mut looping_obstacles: HashSet<Coord> = HashSet::new();
+// Every obstacle placement worth trying, with where the guard stands and faces just before it
+// would run into it. Only cells on the guard's original path can change where it goes, and each is
+// tried from the first time the guard is about to step onto it, since an obstacle there would have
+// stopped it then.
+fn obstacle_trials(grid: &Grid<char>) -> Vec<(Point, Point, Direction)> {
+    let mut tried = grid.map(|_| false);
+    let mut position = find_guard(grid);
+    let mut direction = Direction::Up;
+    *tried.get_mut(position).unwrap() = true;
+    let mut trials = Vec::new();
     loop {
-        for coord in (Griderator {
-            grid: &grid,
-            position: cur_pos,
-            direction_index,
-        }) {
-            let Some((column, row)) = try_usize(coord) else {
-                panic!("invalid coord");
-            };
-            // simulate a run from cur_pos as if there was an obstacle at coord. If it forms a loop, add to the looping_obstacles.
-            // Skip anytime the current next and current position are equal. The iterator logic for Griderator means that the last value of one griderator will be the first value of the next griderator.
-            if !looping_obstacles.contains(&coord) && coord != cur_pos {
-                // println!("testing loop at {:?}", coord);
-                let mut modified_grid = grid.clone();
-                // add simulated obstacle
-                modified_grid[row][column] = '#';
-                if detect_loop(&modified_grid) {
-                    // println!("loop detected at: {:?}", coord);
-                    looping_obstacles.insert(coord);
+        let ahead = position + direction;
+        match grid.get(ahead) {
+            None => break,
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => {
+                let tried = tried.get_mut(ahead).unwrap();
+                if !*tried {
+                    *tried = true;
+                    trials.push((ahead, position, direction));
                 }
+                position = ahead;
             }
-            cur_pos = coord;
-        }
-        if !on_map(cur_pos, &grid, direction_index) {
-            break;
         }
-        direction_index = (direction_index + 1) % 4;
     }
-    looping_obstacles.len().try_into().unwrap()
+    trials
+}
+
+// Counts every obstacle placement that traps the guard in a loop. The trials don't depend on each
+// other, so they can run in parallel.
+fn count_looping_obstacles(grid: &Grid<char>) -> i32 {
+    let jumps = JumpTable::new(grid);
+    parallel::map(
+        &obstacle_trials(grid),
+        |&(obstacle, position, direction)| loops_with(&jumps, obstacle, position, direction),
+    )
+    .into_iter()
+    .filter(|&loops| loops)
+    .count() as i32
 }
 
-pub fn main() {
-    println!("{:?}", solve_complex(INPUT_1));
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Grid<char>;
+    type Answer = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        process_input(input)
+    }
+
+    fn part_one(&self, grid: &Self::Input) -> i32 {
+        count_visited(grid)
+    }
+
+    fn part_two(&self, grid: &Self::Input) -> i32 {
+        count_looping_obstacles(grid)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::{example, puzzle};
+    use crate::solution::{solve, Part};
 
     // Puzzle tests
     #[test]
     fn solve_simple_input_1() {
-        assert_eq!(solve_simple(INPUT_1), 41);
+        assert_eq!(solve(&Solver, &example(2024, 6, 1), Part::One), 41);
     }
 
     #[test]
     fn solve_simple_input_2() {
-        assert_eq!(solve_simple(INPUT_2), 5461);
+        assert_eq!(solve(&Solver, &puzzle(2024, 6), Part::One), 5461);
     }
 
-    // #[test]
-    // fn solve_complex_input_1() {
-    //     assert_eq!(solve_complex(INPUT_1), 6);
-    // }
+    #[test]
+    fn solve_complex_input_1() {
+        assert_eq!(solve(&Solver, &example(2024, 6, 1), Part::Two), 6);
+    }
 
     #[test]
     fn solve_complex_input_2() {
-        assert_eq!(solve_complex(INPUT_2), 1836);
+        assert_eq!(solve(&Solver, &puzzle(2024, 6), Part::Two), 1836);
     }
 
     // function tests
@@ -245,8 +253,8 @@ mod tests {
         ^.#
         ...
         "#;
-        let grid = process_input(grid);
-        assert_eq!(find_guard(&grid), Coord(0, 1));
+        let grid = process_input(grid).unwrap();
+        assert_eq!(find_guard(&grid), Point::new(0, 1));
     }
 
     #[test]
@@ -256,14 +264,14 @@ mod tests {
         ^.#
         ...
         "#;
-        let grid = process_input(grid);
+        let grid = process_input(grid).unwrap();
         let griderator = Griderator {
             grid: &grid,
-            position: Coord(0, 1),
-            direction_index: 1,
+            position: Point::new(0, 1),
+            direction: Direction::Right,
         };
         let grid_vec: Vec<_> = griderator.collect();
-        assert_eq!(grid_vec, [Coord(0, 1), Coord(1, 1)].to_vec());
+        assert_eq!(grid_vec, [Point::new(0, 1), Point::new(1, 1)].to_vec());
     }
 
     #[test]
@@ -273,8 +281,8 @@ mod tests {
         ..#
         ...
         "#;
-        let grid = process_input(grid);
-        assert_eq!(on_map(Coord(0, 0), &grid, 1), true);
+        let grid = Grid::parse(grid, ".#").unwrap();
+        assert!(on_map(Point::new(0, 0), &grid, Direction::Right));
     }
 
     #[test]
@@ -284,8 +292,8 @@ mod tests {
         ..#
         ...
         "#;
-        let grid = process_input(grid);
-        assert_eq!(on_map(Coord(0, 0), &grid, 0), false);
+        let grid = Grid::parse(grid, ".#").unwrap();
+        assert!(!on_map(Point::new(0, 0), &grid, Direction::Up));
     }
 
     #[test]
@@ -295,153 +303,86 @@ mod tests {
         ..#
         ...
         "#;
-        let grid = process_input(grid);
-        assert_eq!(on_map(Coord(2, 0), &grid, 1), false);
+        let grid = Grid::parse(grid, ".#").unwrap();
+        assert!(!on_map(Point::new(2, 0), &grid, Direction::Right));
     }
-}
 
-const INPUT_1: &str = r#"
-....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...
-"#;
-
-const INPUT_2: &str = r#"
-....#.................#......................#..........................#..................#....##..#...........#.................
-...................................#...............................#......#..#...............................#....................
-..........................#................#......##.....#.....................................#...............#..#...............
-.......................................................................................................#..........................
-...................#....#.........................#..............#.....#......................................................#...
-.....#.........................................................................................................#..................
-.........................................................#....................#..#............#................#..................
-..............#...............#..................................................................#......#.........................
-.........#.....#.......#......#.......................#.............#..#........#.......#............#.......#....#..#.......#....
-........................#....#...............................................#...#.#.........................................#....
-.........................#........................................................................#.....................#.........
-.#..........#...#...............#..................#...#......#.................................#....................#.......#....
-............................................................#......#.........................................................#....
-.......................#...#..................#.#.............#......................#.............#..............................
-............................................................................................................#.........#...........
-....#..................###........#.............................#.#....................#..........#.....................#.........
-................................................................................................#.......................#.#.......
-..........................................................................#........#...#.................#.......#................
-..........................#.#..#.#...............................................#..........#..............................#......
-..............#........#.#...............#............................#..........#....................................#........#.#
-#..................................................................................................#...........#................#.
-...............................................#........................................#.................................#.......
-.....#..#......#.......#..............#........#........#...........................................................#.............
-.......#..........#.......#.#...........#................#.................................................#......................
-....#.....#...............#....#.......#...#.............................................................#...................#....
-............................................#....#.......#................................#......#......................#.....#...
-.....................#...................................#....#...........................................................#.......
-..#..............................#...........#...........................#.................................#......................
-........#.............................##...................#.....................#................................................
-................#.#.#......#...............................................#...###.........#....#.................................
-.......#.............#.............#...........#..#...............................#..................................#.....#.....#
-.....#...#.......#.........................................#..............................#........................#.............#
-...............................................#.....#..............................................................#.............
-.........#.......................................#.......................#...........#............................................
-.......................#.....#.....................................................#....................................#.........
-.............................#......................#.....................#..............#..#.......................#.............
-......#......................................#.......#.....................#.....#...........#..........#.....................#...
-..................................................#....................#.......#....#.......#...#.................................
-......................................#......#........#.....#........................................#.............#..............
-#......................#.#..............#.....#.........#...............................#...............................#.........
-#......#...............#.........................................................................................#..#.............
-.#..............................................#.................#.......#.......................................................
-...#.......#.....#.............#...................................................................................#..............
-..........................#........#........#.....................................#........................#..............#..#....
-.......#..........#.......#...................................................#.##.....#.#.........#....#.................#.......
-..##...................#.......................................#..............#.#........#........................................
-#.......#..............#...........#.....#....#....#............#........#..................................#.#.........#.........
-..................#.......................................................................................................#.......
-............................#.........#....................#...............................#..........#...........................
-.............#.....................................................................#.....#.................#......................
-...#...#............#..........................#..........................................#.......................................
-..#.............#.#........................#...#.......................#.........#....................##....#.....................
-...........................................................................................................#.........#...#........
-.............................#.#..........................................................................................#.......
-....................#........................#.#......#...............#......#.............................#......................
-....#...#.......#..................#..........................................................#........................#..........
-.#...........#..............................................#...........................................#.........................
-..............................#...........#...........................................#.........#...........................#....#
-............#.......#......#....#.......................................................................#.........................
-.........................................#...#..................#..................#.....................................#........
-................#..................#........#.........................#...................#......................................#
-............................................................#.....#........#......................................................
-..............................#............#.........................................#.............#.......#..#...................
-...#............#........................................................................#..................................#....#
-..............................#.......#.............#.................................................#...........................
-.....#.................................................#..........#...............................#..##..........................#
-.............#.........................................................#.............................#..#..............#........#.
-....................#...............#................................................................................#....#......#
-........#....#..#...........................................................................#................#..............#.....
-....#......................#...............#..........#......#.........#..^....#..........#..................#.......#............
-...........................#.#...................................................#...............................#................
-#..#.........................................................#.....#........................#.....................................
-.#.#....#.......................................#......#..........#................#..#...........................................
-...#.....#..........#...................#.......#............................#....................................................
-.............#......................................................#............#.....................#.....................#....
-...................#.............................................................................................#................
-...................#.....#...................................................................................................#....
-................#............#......................#.......#..................#..#.....................#..............#........#.
-#....#...................................................................#.......##....#..........#...............................
-.........................................#............#......#.......................#........#..............#.#..................
-.##........#.....#...........................................................................................#..............#.....
-#...........................................................#.......#....#......#..#...................................#..#.#.....
-.............#........................................#...............................#...........................................
-......................#..................................................................#..................................#.....
-...#.#........................................#.................................................................................#.
-.............#.......................#............................#.#................................#.......#..#...........#.....
-...#..#...............................................................................#................#..........................
-.......#.............................#............#..#........#.....#.........................#..................#................
-......#...............................................................................##.................................#....#...
-........#..................#.......................................................................#......#..........#............
-...........#.......#........#..........................................#........#..............#.................#.........#......
-...................#..............................#...............................#.....................#.....#...................
-.............#..#..................#.#..#.....#..#..........................................................................#.....
-..............................#...................................................#.......................#..............#........
-.......#......................#.............................#...........................#.....#...#.................#........#....
-.........#.......#....##..........................................................................................................
-........................................................................#......................#..................................
-.......................#..........##.#................................................#..#.....#......#......#.................#..
-......................................#......................................................................#....................
-.....................................#....#...............................................#...#.....#.......#...#...........#.....
-...................#..#................................................................................#..........................
-..............#.................#..............................#..............#...........#.#..................#...#.............#
-................#..#......#.........................#.....#.....#..............#..#.........................................#.....
-.....#.........................#........................................#.....#.......#...........#...................#...........
-......#....................##......................................................................................#..............
-............................................................................###...............................#...................
-#..................................#......#..............................................................................#.......#
-.....#...................#.....#............................................................#.....................#...#...........
-.......#......#........................#.....................#.#..##............#......#.................#.....................#..
-.....#..............#.................#........#..................#..#..........................##.....#..#...................#...
-........#................#................#...........#.........................................#....................#............
-.........#.........#....#................#........#........#...............#....#.................................................
-...........#...#............#..................#.............................#.#..#....................#.#........................
-.#............#..#..#................#.#................................................................##.............#..........
-...........#.......................#.........................................................#....................................
-..#..........................#...........#......#................................#.#..............................................
-............................................................#..................#...........#.....##.................#.............
-....#.........#............................##..........................................#.....#....#..........#....................
-..................#..............#........#........#....................#..............#....................#.....................
-....#...................................#.....................................................#.............#...................#.
-.........#...........#...#.............................#.............##..#................................................#.#.....
-............................##..............#................#..#........................................#....................#...
-.............................#.#......................................................##.....#..................................#.
-.............................................................................#..................#...#.................#...........
-........................#...........#..................#......#...................................................................
-.....#.......................#......#................#............#..................#.................................#..........
-#..........#...................................................................#...................#...#...............#..........
-....................#............................................#...............#........................##....#..#..............
-.#.....#............................#...............#.#.#..........................#.........#..............#.....................
-.............................#....................................#..#......#........................#............................
-"#;
+    #[test]
+    fn jump_table_stops_before_obstacles() {
+        let grid = r#"
+        ..#
+        ^..
+        ..#
+        "#;
+        let grid = process_input(grid).unwrap();
+        let jumps = JumpTable::new(&grid);
+        let nowhere = Point::new(-5, -5);
+        assert_eq!(
+            jumps.next_stop(Point::new(2, 1), Direction::Up, nowhere),
+            Some(Point::new(2, 1))
+        );
+        assert_eq!(
+            jumps.next_stop(Point::new(0, 1), Direction::Right, nowhere),
+            None
+        );
+        assert_eq!(
+            jumps.next_stop(Point::new(1, 2), Direction::Right, nowhere),
+            Some(Point::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn jump_table_stops_before_added_obstacle() {
+        let grid = r#"
+        .....
+        ^....
+        ..#..
+        "#;
+        let grid = process_input(grid).unwrap();
+        let jumps = JumpTable::new(&grid);
+        let start = Point::new(0, 1);
+        assert_eq!(
+            jumps.next_stop(start, Direction::Right, Point::new(2, 1)),
+            Some(Point::new(1, 1))
+        );
+        // behind the guard, beside its path, or past the next obstacle, so it makes no difference.
+        assert_eq!(
+            jumps.next_stop(start, Direction::Down, Point::new(0, 0)),
+            None
+        );
+        assert_eq!(
+            jumps.next_stop(start, Direction::Right, Point::new(1, 0)),
+            None
+        );
+        let row = Point::new(0, 2);
+        assert_eq!(
+            jumps.next_stop(row, Direction::Right, Point::new(4, 2)),
+            Some(Point::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn obstacle_closes_loop() {
+        let grid = r#"
+        .#..
+        ...#
+        ....
+        ..#^
+        "#;
+        let grid = process_input(grid).unwrap();
+        let jumps = JumpTable::new(&grid);
+        // the obstacle turns the guard at (1, 2) onto the square (1, 1), (2, 1), (2, 2), (1, 2).
+        assert!(loops_with(
+            &jumps,
+            Point::new(0, 2),
+            Point::new(1, 2),
+            Direction::Left
+        ));
+        assert!(!loops_with(
+            &jumps,
+            Point::new(3, 2),
+            Point::new(0, 2),
+            Direction::Up
+        ));
+    }
+}
@@ -1,973 +1,594 @@
 // https://adventofcode.com/2024/day/7
+use std::{
+    fmt::{self, Display},
+    ops::{Div, Rem},
+    str::FromStr,
+};
 
-struct Entry {
-    target: isize,
-    elements: Vec<isize>,
+use crate::{
+    parallel,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Entry<N> {
+    target: N,
+    elements: Vec<N>,
+}
+
+// Equations are worked out in u64 unless a number in the input doesn't fit, in which case the whole
+// input is read as u128 instead.
+#[derive(Debug)]
+pub enum Equations {
+    Narrow(Vec<Entry<u64>>),
+    Wide(Vec<Entry<u128>>),
 }
 
-fn process_input(input: &str) -> Vec<Entry> {
+fn parse_equations(input: &str) -> Result<Equations, ParseError> {
+    match process_input(input) {
+        Ok(entries) => Ok(Equations::Narrow(entries)),
+        Err(_) => process_input(input).map(Equations::Wide),
+    }
+}
+
+fn process_input<N: Number>(input: &str) -> Result<Vec<Entry<N>>, ParseError> {
     input
         .trim()
         .lines()
         .map(|line| match line.trim().split_once(": ") {
             Some((target, elements)) => {
-                let target = target.parse().unwrap();
-                let elements = elements.split(' ').map(|n| n.parse().unwrap()).collect();
-                Entry { target, elements }
+                let target = parse::number(input, target)?;
+                let elements = elements
+                    .split(' ')
+                    .map(|n| parse::number(input, n))
+                    .collect::<Result<_, _>>()?;
+                Ok(Entry { target, elements })
             }
-            None => panic!("first split failed."),
+            None => Err(ParseError::at(
+                input,
+                line,
+                "expected a test value, \": \" and numbers",
+            )),
         })
         .collect()
 }
 
-fn operator_check(entry: &Entry) -> bool {
-    fn inner(target: isize, total: isize, elements: &[isize]) -> bool {
-        if total > target {
-            return false;
+// The unsigned integers equations can be worked out in. Anything that can grow is checked, so an
+// overflow comes back as None and the branch is dropped instead of wrapping or panicking.
+trait Number:
+    Copy
+    + Ord
+    + Display
+    + FromStr
+    + Into<u128>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Send
+    + Sync
+    + 'static
+{
+    const ZERO: Self;
+    const TEN: Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! number {
+    ($($t:ty),*) => {
+        $(impl Number for $t {
+            const ZERO: $t = 0;
+            const TEN: $t = 10;
+
+            fn checked_add(self, other: $t) -> Option<$t> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: $t) -> Option<$t> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: $t) -> Option<$t> {
+                <$t>::checked_mul(self, other)
+            }
+        })*
+    };
+}
+
+number!(u64, u128);
+
+// A way of combining the running total with the next number, along with its inverse so equations
+// can be worked back from the target.
+trait Operator<N>: Sync {
+    fn symbol(&self) -> &'static str;
+    // None if the result doesn't fit in N.
+    fn apply(&self, total: N, next: N) -> Option<N>;
+    // The total that, combined with next, gives result, or None if no single total does.
+    fn unapply(&self, result: N, next: N) -> Option<N>;
+    // Whether every total combined with next gives result, which unapply can't express.
+    fn absorbs(&self, _result: N, _next: N) -> bool {
+        false
+    }
+}
+
+struct Add;
+struct Multiply;
+struct Concat;
+
+impl<N: Number> Operator<N> for Add {
+    fn symbol(&self) -> &'static str {
+        "+"
+    }
+
+    fn apply(&self, total: N, next: N) -> Option<N> {
+        total.checked_add(next)
+    }
+
+    fn unapply(&self, result: N, next: N) -> Option<N> {
+        result.checked_sub(next)
+    }
+}
+
+impl<N: Number> Operator<N> for Multiply {
+    fn symbol(&self) -> &'static str {
+        "*"
+    }
+
+    fn apply(&self, total: N, next: N) -> Option<N> {
+        total.checked_mul(next)
+    }
+
+    fn unapply(&self, result: N, next: N) -> Option<N> {
+        (next != N::ZERO && result % next == N::ZERO).then(|| result / next)
+    }
+
+    // Anything times zero is zero.
+    fn absorbs(&self, result: N, next: N) -> bool {
+        result == N::ZERO && next == N::ZERO
+    }
+}
+
+// The power of ten that shifts a number left far enough to append n's digits, or None if that
+// doesn't fit in N.
+fn digit_shift<N: Number>(n: N) -> Option<N> {
+    let mut shift = N::TEN;
+    while shift <= n {
+        shift = shift.checked_mul(N::TEN)?;
+    }
+    Some(shift)
+}
+
+impl<N: Number> Operator<N> for Concat {
+    fn symbol(&self) -> &'static str {
+        "||"
+    }
+
+    fn apply(&self, total: N, next: N) -> Option<N> {
+        total.checked_mul(digit_shift(next)?)?.checked_add(next)
+    }
+
+    // Strips next's digits off the end of result. When next is too long to shift past, only a
+    // total of zero can have been in front of it.
+    fn unapply(&self, result: N, next: N) -> Option<N> {
+        match digit_shift(next) {
+            Some(shift) => (result % shift == next).then(|| result / shift),
+            None => (result == next).then_some(N::ZERO),
         }
+    }
+}
+
+fn add_multiply<N: Number>() -> [&'static dyn Operator<N>; 2] {
+    [&Add, &Multiply]
+}
+
+fn with_concat<N: Number>() -> [&'static dyn Operator<N>; 3] {
+    [&Add, &Multiply, &Concat]
+}
+
+// Operators that combine the elements left to right into the target, if any do. The search starts
+// from the target and the last element and undoes one operator at a time, so a branch ends as soon
+// as an operator can't have produced the total so far.
+fn find_operators<'a, N: Number>(
+    entry: &Entry<N>,
+    operators: &[&'a dyn Operator<N>],
+) -> Option<Vec<&'a dyn Operator<N>>> {
+    fn search<'a, N: Number>(
+        target: N,
+        elements: &[N],
+        operators: &[&'a dyn Operator<N>],
+        chosen: &mut Vec<&'a dyn Operator<N>>,
+    ) -> bool {
         match elements {
-            [] => total == target,
-            [head, tail @ ..] => {
-                inner(target, total * head, tail) || inner(target, total + head, tail)
-            }
+            [] => false,
+            [first] => *first == target,
+            [rest @ .., last] => operators.iter().any(|&operator| {
+                if operator.absorbs(target, *last) {
+                    // whatever the rest comes to works, as long as it doesn't overflow.
+                    let mut forward = Vec::new();
+                    if evaluates(rest[0], &rest[1..], operators, &mut forward) {
+                        chosen.push(operator);
+                        chosen.extend(forward.into_iter().rev());
+                        return true;
+                    }
+                }
+                let Some(total) = operator.unapply(target, *last) else {
+                    return false;
+                };
+                chosen.push(operator);
+                if search(total, rest, operators, chosen) {
+                    return true;
+                }
+                chosen.pop();
+                false
+            }),
         }
     }
-    match &entry.elements[..] {
-        [] => false,
-        [a] => *a == entry.target,
-        [head, tail @ ..] => inner(entry.target, *head, tail),
+    // Finds operators, first to last, that combine total with the elements without overflowing.
+    fn evaluates<'a, N: Number>(
+        total: N,
+        elements: &[N],
+        operators: &[&'a dyn Operator<N>],
+        chosen: &mut Vec<&'a dyn Operator<N>>,
+    ) -> bool {
+        let [next, rest @ ..] = elements else {
+            return true;
+        };
+        operators.iter().any(|&operator| {
+            let Some(total) = operator.apply(total, *next) else {
+                return false;
+            };
+            chosen.push(operator);
+            if evaluates(total, rest, operators, chosen) {
+                return true;
+            }
+            chosen.pop();
+            false
+        })
+    }
+    let mut chosen = Vec::new();
+    if !search(entry.target, &entry.elements, operators, &mut chosen) {
+        return None;
     }
+    // found from the last operator back to the first.
+    chosen.reverse();
+    debug_assert!(evaluate(&entry.elements, &chosen) == Some(entry.target));
+    Some(chosen)
 }
 
-fn solve_simple(input: &str) -> isize {
-    let entries = process_input(input);
-    entries
+// Combines the elements left to right with one operator between each pair, or None if that
+// overflows along the way.
+fn evaluate<N: Number>(elements: &[N], operators: &[&dyn Operator<N>]) -> Option<N> {
+    operators
         .iter()
-        .filter(|entry| operator_check(entry))
-        .map(|entry| entry.target)
-        .sum()
+        .zip(&elements[1..])
+        .try_fold(elements[0], |total, (operator, &next)| {
+            operator.apply(total, next)
+        })
+}
+
+// Writes an entry out as an equation, like "3267 = 81 + 40 * 27".
+fn equation<N: Number>(entry: &Entry<N>, operators: &[&dyn Operator<N>]) -> String {
+    let mut equation = format!("{} = {}", entry.target, entry.elements[0]);
+    for (operator, element) in operators.iter().zip(&entry.elements[1..]) {
+        equation += &format!(" {} {}", operator.symbol(), element);
+    }
+    equation
 }
 
-fn num_concat(lhs: isize, rhs: isize) -> isize {
-    (lhs.to_string() + &rhs.to_string()).parse().unwrap()
+// The sum of the targets that can be made true. Enough wide targets add up to more than u128 holds.
+#[derive(Debug, PartialEq)]
+pub enum Total {
+    Sum(u128),
+    Overflow,
 }
 
-fn operator_check_complex(entry: &Entry) -> bool {
-    fn inner(target: isize, total: isize, elements: &[isize]) -> bool {
-        match elements {
-            [] => total == target,
-            [head, tail @ ..] => {
-                inner(target, num_concat(total, *head), tail)
-                    || inner(target, total * head, tail)
-                    || inner(target, total + head, tail)
-            }
+impl Display for Total {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Total::Sum(sum) => write!(f, "{}", sum),
+            Total::Overflow => write!(f, "more than {}", u128::MAX),
         }
     }
-    match &entry.elements[..] {
-        [] => false,
-        [a] => *a == entry.target,
-        [head, tail @ ..] => inner(entry.target, *head, tail),
-    }
 }
 
-fn solve_complex(input: &str) -> isize {
-    let entries = process_input(input);
+fn calibration<N: Number>(entries: &[Entry<N>], operators: &[&dyn Operator<N>]) -> Total {
+    parallel::map(entries, |entry| match find_operators(entry, operators) {
+        Some(_) => entry.target.into(),
+        None => 0,
+    })
+    .into_iter()
+    .try_fold(0, u128::checked_add)
+    .map_or(Total::Overflow, Total::Sum)
+}
+
+// The operators that make each entry true, without concatenation where it isn't needed.
+fn report<N: Number>(entries: &[Entry<N>]) -> String {
     entries
         .iter()
-        .filter(|entry| operator_check_complex(entry))
-        .map(|entry| entry.target)
-        .sum()
+        .map(|entry| {
+            match find_operators(entry, &add_multiply())
+                .or_else(|| find_operators(entry, &with_concat()))
+            {
+                Some(operators) => format!("{}\n", equation(entry, &operators)),
+                None => format!("{}: no operators work\n", entry.target),
+            }
+        })
+        .collect()
 }
 
-pub fn main() {}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Equations;
+    type Answer = Total;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_equations(input)
+    }
+
+    fn part_one(&self, equations: &Self::Input) -> Total {
+        match equations {
+            Equations::Narrow(entries) => calibration(entries, &add_multiply()),
+            Equations::Wide(entries) => calibration(entries, &add_multiply()),
+        }
+    }
+
+    fn part_two(&self, equations: &Self::Input) -> Total {
+        match equations {
+            Equations::Narrow(entries) => calibration(entries, &with_concat()),
+            Equations::Wide(entries) => calibration(entries, &with_concat()),
+        }
+    }
+
+    fn report(&self, equations: &Self::Input) -> Option<String> {
+        Some(match equations {
+            Equations::Narrow(entries) => report(entries),
+            Equations::Wide(entries) => report(entries),
+        })
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::{example, puzzle};
+    use crate::solution::{solve, Part};
+
+    #[test]
+    fn reject_missing_separator() {
+        let error = Solver.parse("190: 10 19\n3267 81 40 27\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "3267 81 40 27");
+    }
+
+    #[test]
+    fn reject_bad_number() {
+        let error = Solver.parse("190: 10 19\n3267: 81 4O 27\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+    }
+
+    #[test]
+    fn reject_negative_number() {
+        let error = Solver.parse("190: 10 19\n3267: 81 -40 27\n").unwrap_err();
+        assert_eq!(error.text, "-40");
+    }
+
+    fn symbols<N: Number>(
+        entry: &str,
+        operators: &[&dyn Operator<N>],
+    ) -> Option<Vec<&'static str>> {
+        let entries = process_input(entry).unwrap();
+        find_operators(&entries[0], operators)
+            .map(|chosen| chosen.iter().map(|operator| operator.symbol()).collect())
+    }
+
+    #[test]
+    fn operators_undo_themselves() {
+        for operator in with_concat::<u64>() {
+            for (total, next) in [(0, 7), (12, 345), (6, 10), (81, 1)] {
+                let result = operator.apply(total, next).unwrap();
+                assert_eq!(
+                    operator.unapply(result, next),
+                    Some(total),
+                    "{}",
+                    operator.symbol()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn operators_reject_impossible_totals() {
+        assert_eq!(Operator::<u64>::unapply(&Add, 3, 5), None);
+        assert_eq!(Operator::<u64>::unapply(&Multiply, 10, 3), None);
+        assert_eq!(Operator::<u64>::unapply(&Concat, 1234, 35), None);
+        assert_eq!(Operator::<u64>::unapply(&Concat, 1234, 34), Some(12));
+        assert_eq!(Operator::<u64>::apply(&Concat, 15, 6), Some(156));
+    }
+
+    #[test]
+    fn operators_report_overflow() {
+        assert_eq!(Operator::<u64>::apply(&Add, u64::MAX, 1), None);
+        assert_eq!(Operator::<u64>::apply(&Multiply, 1 << 32, 1 << 32), None);
+        assert_eq!(Operator::<u64>::apply(&Concat, u64::MAX / 10, 99), None);
+        assert_eq!(digit_shift(u64::MAX), None);
+        // nothing but zero fits in front of a number that long.
+        assert_eq!(
+            Operator::<u64>::unapply(&Concat, u64::MAX, u64::MAX),
+            Some(0)
+        );
+        assert_eq!(
+            Operator::<u64>::unapply(&Concat, u64::MAX, u64::MAX - 1),
+            None
+        );
+    }
+
+    #[test]
+    fn find_operator_sequence() {
+        assert_eq!(
+            symbols::<u64>("190: 10 19", &add_multiply()),
+            Some(vec!["*"])
+        );
+        assert_eq!(
+            symbols::<u64>("292: 11 6 16 20", &add_multiply()),
+            Some(vec!["+", "*", "+"])
+        );
+        assert_eq!(symbols::<u64>("156: 15 6", &add_multiply()), None);
+        assert_eq!(
+            symbols::<u64>("156: 15 6", &with_concat()),
+            Some(vec!["||"])
+        );
+        assert_eq!(symbols::<u64>("7: 7", &add_multiply()), Some(vec![]));
+    }
+
+    #[test]
+    fn multiply_by_zero() {
+        assert_eq!(symbols::<u64>("0: 5 0", &add_multiply()), Some(vec!["*"]));
+        assert_eq!(
+            symbols::<u64>("7: 3 0 7", &add_multiply()),
+            Some(vec!["*", "+"])
+        );
+        assert_eq!(
+            symbols::<u64>("0: 4 5 0", &add_multiply()),
+            Some(vec!["+", "*"])
+        );
+        assert_eq!(symbols::<u64>("1: 5 0", &add_multiply()), None);
+        // the rest still has to come to something that fits.
+        let overflow = "0: 18446744073709551615 2 0";
+        assert_eq!(symbols::<u64>(overflow, &with_concat()), None);
+        assert_eq!(
+            solve(&Solver, "7: 3 0 7\n0: 5 0\n", Part::One),
+            Total::Sum(7)
+        );
+    }
+
+    #[test]
+    fn prune_overflowing_branches() {
+        // 2^32 * 2^32 is one past u64::MAX, so only adding can get there.
+        let max = "18446744073709551615: 4294967296 4294967296";
+        assert_eq!(symbols::<u64>(max, &with_concat()), None);
+        let max = "18446744073709551615: 18446744073709551614 1";
+        assert_eq!(symbols::<u64>(max, &with_concat()), Some(vec!["+"]));
+    }
+
+    #[test]
+    fn evaluate_left_to_right() {
+        assert_eq!(
+            evaluate(&[11u64, 6, 16, 20], &[&Add, &Multiply, &Add]),
+            Some(292)
+        );
+        assert_eq!(
+            evaluate(&[6u64, 8, 6, 15], &[&Multiply, &Concat, &Multiply]),
+            Some(7290)
+        );
+        assert_eq!(evaluate(&[u64::MAX, 2], &[&Multiply]), None);
+    }
+
+    #[test]
+    fn narrow_unless_numbers_are_too_large() {
+        // 2^63 is just past i64::MAX but still fits in u64.
+        let equations = Solver
+            .parse("9223372036854775808: 4294967296 2147483648\n")
+            .unwrap();
+        assert!(matches!(equations, Equations::Narrow(_)));
+        assert_eq!(Solver.part_one(&equations), Total::Sum(1 << 63));
+        let equations = Solver
+            .parse("190: 10 19\n100000000000000000000: 1 2\n")
+            .unwrap();
+        assert!(matches!(equations, Equations::Wide(_)));
+    }
+
+    #[test]
+    fn solve_wide_equations() {
+        let input = "
+            100000000000000000000: 10000000000 10000000000
+            123456789012345678901234: 123456789012 345678901234
+            340282366920938463463374607431768211455: 18446744073709551616 18446744073709551616
+            18446744073709551616: 9223372036854775808 2
+            ";
+        let equations = Solver.parse(input).unwrap();
+        assert!(matches!(equations, Equations::Wide(_)));
+        assert_eq!(
+            Solver.part_one(&equations),
+            Total::Sum(100000000000000000000 + (1 << 64))
+        );
+        assert_eq!(
+            Solver.part_two(&equations),
+            Total::Sum(100000000000000000000 + 123456789012345678901234 + (1 << 64))
+        );
+        assert_eq!(
+            Solver.report(&equations).unwrap(),
+            "100000000000000000000 = 10000000000 * 10000000000\n\
+             123456789012345678901234 = 123456789012 || 345678901234\n\
+             340282366920938463463374607431768211455: no operators work\n\
+             18446744073709551616 = 9223372036854775808 * 2\n"
+        );
+    }
+
+    #[test]
+    fn report_total_overflow() {
+        let line =
+            "340282366920938463463374607431768211455: 340282366920938463463374607431768211455\n";
+        let equations = Solver.parse(&line.repeat(2)).unwrap();
+        assert_eq!(Solver.part_one(&equations), Total::Overflow);
+        assert_eq!(
+            Solver.part_two(&equations).to_string(),
+            "more than 340282366920938463463374607431768211455"
+        );
+        let equations = Solver.parse(line).unwrap();
+        assert_eq!(Solver.part_one(&equations), Total::Sum(u128::MAX));
+    }
+
+    #[test]
+    fn reject_numbers_too_large_for_u128() {
+        let error = Solver
+            .parse("190: 10 19\n340282366920938463463374607431768211456: 1 2\n")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn report_equations() {
+        let equations = Solver
+            .parse("3267: 81 40 27\n7290: 6 8 6 15\n83: 17 5\n")
+            .unwrap();
+        assert_eq!(
+            Solver.report(&equations).unwrap(),
+            "3267 = 81 * 40 + 27\n7290 = 6 * 8 || 6 * 15\n83: no operators work\n"
+        );
+    }
 
     //Puzzle tests
     #[test]
     fn solve_simple_input_1() {
-        assert_eq!(solve_simple(INPUT_1), 3749);
+        assert_eq!(
+            solve(&Solver, &example(2024, 7, 1), Part::One),
+            Total::Sum(3749)
+        );
     }
 
     #[test]
     fn solve_simple_input_2() {
-        assert_eq!(solve_simple(INPUT_2), 7885693428401);
+        assert_eq!(
+            solve(&Solver, &puzzle(2024, 7), Part::One),
+            Total::Sum(7885693428401)
+        );
     }
 
     #[test]
     fn solve_complex_input_1() {
-        assert_eq!(solve_complex(INPUT_1), 11387);
+        assert_eq!(
+            solve(&Solver, &example(2024, 7, 1), Part::Two),
+            Total::Sum(11387)
+        );
     }
 
     #[test]
     fn solve_complex_input_2() {
-        assert_eq!(solve_complex(INPUT_2), 348360680516005);
+        assert_eq!(
+            solve(&Solver, &puzzle(2024, 7), Part::Two),
+            Total::Sum(348360680516005)
+        );
     }
 }
-
-const INPUT_1: &str = r#"
-190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20
-"#;
-
-const INPUT_2: &str = r#"
-80453: 65 232 17 4 253 1 1
-11072880: 9 5 91 338 8
-251359026: 801 36 2 1 4 7 9 34 3 3
-5146105932: 3 4 73 2 105 4 1 8 6 4 8
-3217192: 32 99 49 156 33
-1240885: 980 2 568 2 2 4 85
-761411281: 8 13 16 4 1 79 76 3 6 5 8
-1773078: 300 75 788 6 78
-363734176: 5 10 32 928 2 7 177 1 1
-44388674: 64 8 9 82 4 49 75
-2405604276: 3 9 9 4 85 9 433 3 991 7
-693989: 693 333 1 30 628
-13925303275: 7 909 38 6 4 186 327 5
-63263200: 63 7 79 16 715
-8544146: 58 33 5 89 147
-869069580: 564 99 233 31 16 5
-14736199074: 5 997 63 9 3 819 4 46
-61182660: 3 3 2 95 77 697
-8680: 4 84 376 7 8
-1796382720: 934 9 62 9 6 6 64 5 1 2 8
-1158: 100 968 8 74 6
-309536: 4 64 569 4 2
-98070674452: 7 815 1 191 6 30 3 3 2 2
-765702: 6 3 85 70 3
-486500: 44 2 37 556 7
-857641310086: 653 1 1 4 9 41 8 7 5 4 8 8
-12655: 970 2 81 63 6 151
-11037: 5 8 5 49 9 657 1 1 567
-15426904: 6 9 156 2 7 5 74 14 86 3
-255584836: 82 317 56 3 2 5 5 981 1
-2795079846: 9 31 693 260 23 3
-59474651: 9 2 33 1 472 1 42 123 4
-31392: 5 3 8 7 4 22 7 326 709 8
-202335: 3 1 195 2 615 719
-10072161288612: 329 76 973 414 6 12
-304800: 62 8 12 8 75 1
-410694814: 7 74 2 9 9 7 8 4 602 6 22
-37200044757: 590 3 9 5 27 3 7 21 57
-1879341: 238 388 44 6 3 3
-1199889306: 52 8 4 1 9 8 11 8 316 18
-531918519: 9 4 48 1 504 1 8 7 189 8
-13077712: 653 88 52 2 6
-2861487998: 74 58 10 921 379 97
-7476829: 51 274 535 645 94
-162477: 2 799 4 64 9 97
-166412: 7 78 8 9 975 1 3
-451743: 3 4 624 7 6 9 578 7 1 1 2
-92311037059: 9 4 4 9 7 1 9 61 9 91 51 7
-67295279430: 2 82 3 1 4 67 7 99 6 2 9
-8793: 822 1 4 1 10 476 1 39 8
-3794488742: 949 7 425 5 84 8 211 2
-1579094: 2 97 2 5 72 54 2 563 3
-25871540: 4 14 7 871 539
-805: 3 777 23 4
-592118916732: 30 783 6 71 7 24 7 7 9 4
-995405888: 625 5 158 546 9 416
-6335512: 633 509 2 39 9
-24861: 322 77 65
-70077355183: 2 6 685 3 773 551 74 7
-7674310: 64 2 98 595 1
-53167296001: 50 4 50 60 510 10 52 8
-9921503: 21 79 9 91 2 4 3 3 2 9 8 5
-26477554: 7 214 1 9 557 943 2
-4451398: 8 5 2 9 355 9 179 3 6 4 2
-18133152: 3 185 8 87 96
-4075412211: 815 5 3 1 247 875 13
-149028: 749 27 32 6 6
-3932866: 9 25 971 5 9 3 6 6 8 8 12
-2115650020: 77 223 86 61 4 205
-31439100: 9 64 8 19 72 87 7 7 4 75
-16388: 3 27 8 74 87
-1418098: 1 4 47 5 7 7 6 5 6 85
-178644: 9 7 1 864 2
-350280828: 4 15 7 3 713 315 825
-307320304: 4 985 78 30 4
-2362320: 7 5 158 85 144
-142823: 307 9 134 32 1 44
-2721809: 30 907 6 48 8 69 84
-3950727505: 2 20 5 3 13 7 9 5 43 7 5 2
-131968643: 2 9 2 1 4 1 1 903 730 8 2
-2757417345: 851 193 93 4 64 71 1
-572376238: 7 647 87 814 74
-11313873117: 3 36 68 3 7 6 5 786 478
-445454: 25 3 2 88 11 8 50 7
-241: 17 1 8 3 3 7
-265704964: 7 54 2 5 7 1 8 29 2 6 1 4
-25524498: 8 573 348 8 2 14 764 8
-41898086205: 20 94 1 872 31 824
-30006791: 28 6 4 4 5 3 6 8 864 7 2 1
-161840: 44 4 48 8 7 3
-529760419: 9 3 6 8 85 8 1 22 1 208 2
-10827556512: 500 581 1 4 3 5 5 6 515
-278813227280163: 9 968 913 352 50 5 4 8
-107410: 1 4 5 74 11
-111966: 6 703 59 85 391 9 7
-1820799016210: 1 43 9 58 9 9 5 1 1 62 10
-34086: 51 2 6 1 46 2 90 9 409
-658814461: 4 82 68 6 76 8 5 2 784
-6248684908090: 762 965 19 3 7 49 2 39
-49325079: 39 87 367 227 23 73 9
-128180486: 419 8 956 4 6
-12146408: 482 70 3 6 4 2
-46151352: 4 7 1 6 5 5 17 8 8 5 5 950
-268237: 6 51 4 78 37
-75087: 7 149 7 1 213 7 71 469
-2030410: 57 9 10 7 4 554
-8190500163: 21 7 12 9 946 538 7
-4627959995970: 66 7 1 7 959 995 9 73
-472457: 471 180 941 304 33
-2843491: 9 2 1 5 2 59 990 71 9
-1662060690: 281 528 94 83 2 59 8 3
-71528448486: 4 51 6 758 44 2 8 73 5
-159099841: 7 231 81 1 22
-12663773526: 4 2 2 11 6 3 6 47 5 22 2
-927300212: 9 4 4 90 781 2 2 85 388
-6782849: 4 6 5 3 68 8 8 598 1 8 8
-299601: 287 1 83 2 62 355 5 4 2
-51682789: 4 9 44 68 9 9 6 579 5 86
-153393346: 22 66 7 45 9 427
-102623: 89 3 8 48 7 82 6
-27139448: 79 6 3 8 1 681 7 9 8 2 9
-44209555513: 44 209 55 49 6 13
-19102: 9 970 1 1 19 6 96 9 390
-90534: 8 5 830 104 54
-25134318083801: 364 9 984 869 7 803
-84393891730: 7 85 7 405 403 91 3 55
-790: 1 19 5 63 5
-12009921: 3 50 8 9 921
-9781206: 4 473 8 858 6 3
-5218166016: 5 51 5 4 558 2 7 8 6 7 7
-14119: 3 2 435 32 7
-219619443: 586 9 359 114 38 4 9 3
-494506865160: 8 3 4 12 84 7 2 7 8 603 5
-25643796039: 18 694 3 4 5 5 5 2 67 9 7
-6715112: 9 2 8 201 29 1 4 2 4 1 99
-4608: 4 433 1 2 1 1 2 40 5 18
-991684: 5 3 9 77 27
-369627102: 461 342 691 88 8
-12453: 67 2 1 45 9 1 51
-41985868: 4 457 208 9 867
-230112045: 2 14 340 940 5 9 37 8
-1558443235357: 25 4 5 740 3 2 3 9 22 6 6
-7658313312: 2 463 1 31 4 557 897 4
-21551778: 536 7 441 7 9 1 41
-350465040: 2 1 740 2 800 6 4 4 37 4
-99964808: 9 4 534 52 10
-5550930: 52 7 37 238 1 405
-22715297428: 8 739 7 14 782 8 217
-384007419: 320 120 7 41 8
-25916002: 16 42 9 80 550
-80805925: 5 676 2 6 733 4 4 6
-136800: 227 1 5 15 8
-2656155: 9 6 74 53 8 82 9
-16286: 1 7 7 676 61
-1139100: 92 2 146 83 7
-623772543: 529 89 6 51 725 40
-1142: 14 42 23 42 405 84
-50415: 50 841 7 14 30 4 7
-301413: 42 9 6 99 900 92 849
-848103923: 6 14 810 392 4
-53940: 51 54 741 24 62
-144491721762: 1 2 994 46 8 6 3 33 19 7
-45386287: 784 29 437 39 931 37
-10108943: 130 877 3 894 4
-21658: 9 1 5 7 55 47 4 4 82 4 1 2
-759325513853792: 83 95 963 138 5 37 93
-722643: 289 8 4 24 92 153
-399639247996: 3 370 109 36 79 95
-3051058: 40 2 280 11 38
-1202: 4 962 233 1 2
-4727573267: 6 69 9 367 57 1 1 4 70 1
-52677907: 49 461 313 10 33 7
-10050892265: 5 86 35 915 8 922 66
-2987: 34 79 78 217 6
-583746: 583 7 46
-9646: 36 7 38 1 69
-2807723736: 46 137 41 495 9 9
-18826860280: 8 8 547 76 3 1 8 8 1 44 8
-971981682: 97 1 9 453 363 82
-5977403: 8 51 1 77 393 7
-14303180: 528 901 4 9 178
-36134415: 6 4 20 7 7 2 931 463 9
-35109: 38 634 30 50 9
-12606003: 4 4 5 75 764
-17683203483: 5 3 6 73 323 95 4 1 255
-355577: 901 7 795 9 5 8 16
-654126: 7 7 1 6 89 5 8 5 1 5 6 96
-762858: 7 636 3 754 9 152 1 6
-4466521: 67 7 4 6 77 4 57 1
-40257693486: 349 1 2 9 10 8 71 37 4 9
-26709004: 3 4 151 981 84 98 2
-32746679: 71 79 6 648 9
-39625: 1 4 748 52 469
-9709: 97 248 9 617 1
-144459502: 1 164 42 4 23 651 2
-235672: 360 61 128 82 31 4 89
-514215: 8 61 110 30 41 5 4 25 3
-626870234975: 9 3 5 6 25 2 67 14 497 4
-343752250: 902 74 103 1 50
-867: 2 8 2 41 2 45
-229252821: 9 6 339 6 49 919
-335358: 353 95 8
-1260845177: 3 93 45 1 533 515 2 2 5
-35513409: 5 8 74 558 2 31 367
-4811913: 473 7 6 2 2 5 3 45 38 73
-1593555586: 9 122 2 54 2 7 1 1 5 5 8 8
-386835: 187 5 4 6 8 934 205
-6294685256: 9 62 4 113 9 6 7 4 662
-2094621: 9 9 5 826 127 8 6 7 1 6
-223546540: 3 7 5 3 87 6 5 571 28 8 5
-3562: 306 1 501
-3251820: 6 34 5 3 639 6 6 102 9 4
-47425: 4 3 8 80 5 7 8 5 8 1 58 6
-17359545: 577 1 15 3 21 131 1 2 3
-44797453: 57 44 8 44 5 1 4 51
-1167206: 35 6 5 116 427 779
-44153: 6 8 19 659
-661320: 5 5 6 6 5 402 7 6 3 167
-459856: 535 24 1 8 82
-1093763483: 4 2 64 11 7 6 7 685 9 8
-587890: 58 1 1 6 46 1 9 1 79 5 3
-3152812902: 38 9 1 72 4 6 8 4 3 5 70
-991368: 991 3 5 8 7
-26501810: 1 24 356 287 9 27 230
-831526448802: 203 30 72 409 800
-107361118: 3 7 18 93 9 9 509 802
-903435238: 991 2 214 6 355
-2494172365978: 5 42 4 958 42 8 7 29 6 4
-66960781: 9 63 930 7 80
-2954430774: 29 9 62 85 9 723 50 2
-1843044: 1 5 3 959 4 8 17 7 5 7 7 6
-1515944541939: 639 77 237 861 13 6
-15580038: 2 7 69 16 6 24 7 390
-557225206481: 85 72 69 65 27 8 6 478
-663960349137: 4 36 37 6 404 767 111
-446472: 9 9 8 689 1
-55448739: 553 7 7 840 34 1 10
-139151273: 8 6 1 608 2 2 3 8 1 1 27 2
-8745053673: 5 10 83 9 29 6 634 9 3 6
-567594: 4 8 2 3 472 5 7 52 94 4 2
-1563994: 6 4 5 401 78 89 5
-113165: 537 7 6 2 2 1 546 5
-3191: 237 1 745 1 78
-2692218: 10 964 691 4 9 73
-1339821860: 670 33 619 9 9 40 589
-496620: 5 4 2 2 154 587 888 36
-1414877058462: 360 9 3 8 4 1 98 459 2
-1491097042: 1 710 21 1 8 6 945 99
-445501530: 4 45 5 90 142 8 5 25
-647931: 17 90 7 113 7 42 1
-1215: 4 7 1 93 3
-31518901471558: 8 41 851 9 52 3 944 1 8
-1797: 1 5 9 69 8 9 991 8 650 8
-9543: 89 41 43 9 547
-132436687408: 55 3 5 4 583 2 925 8 8 3
-13224041600: 1 4 748 902 784 5 1 1
-33761367370: 722 868 481 2 8 56 6 4
-427416: 46 23 172 36 63 105
-32747264: 13 4 7 1 2 8 4 6 4 8 8 29
-2839: 458 6 7 3 81
-3430168: 921 4 3 66 7 9 4 9 7 6 2 4
-346202395: 341 5 125 77 396
-16819164083: 552 549 555 7 3 7
-41630: 15 793 8 89 46
-48577788812329: 771 9 68 4 7 81 2 3 28
-13311605: 18 739 5 81 376 3 8
-704954971: 747 5 248 540 7 91
-29030550: 7 1 20 3 8 4 4 1 105 39 6
-66521838: 4 371 5 8 310 9 3 8 5 5
-380988: 5 3 39 7 96 3 4 7
-123491364: 17 46 842 84 274
-2025360000: 79 1 1 580 10 45 97
-55161058858: 9 913 636 222 354 5 8
-2507866928: 70 4 457 356 6
-4912132550: 97 1 2 3 5 1 1 4 3 254 9 1
-56991: 32 4 74 2 6 43 6 1 98
-661189130: 7 4 6 2 4 39 9 5 917 5 7 1
-13350852: 6 59 1 5 2 320 839 1 6 9
-6790: 9 1 15 1 8 84 4 62 5
-22855536: 3 3 4 396 614
-199118969: 7 1 5 9 42 554 9 4 7 8 7
-402931407: 36 8 8 5 4 4 1 743 873
-5695722994: 982 58 757 472 94
-54060007: 7 60 15 7 858
-3759488: 4 9 41 87 1 802 9 4 2 8 4
-1719433: 1 625 8 936 31
-3701529415: 790 32 562 45 4 12
-268276291: 46 6 8 5 9 9 762 92
-1129772527: 26 899 7 3 7 2 1 530
-64155: 6 761 7 1 2 2 4 194 20 9
-53740: 9 5 2 3 6 115 9 7 1 704 2
-5177612188: 4 88 457 36 4 70 13 2
-51687: 4 1 8 6 62 4 1 9 908 686
-171158415: 759 41 55 39 18
-160706087: 5 356 86 3 5 9 6 3
-375053: 1 9 164 4 1 57 507 569
-227289829: 2 9 184 5 6 6 10 7 2 98
-371838930252: 37 18 389 2 56 46 51
-363488: 864 7 42 315 1
-1779996: 329 3 90 74 57
-4874444999: 5 193 82 1 5 7 176 7 5 6
-5253: 3 49 5 1 3
-815: 5 3 65 8 5
-38820: 865 44 11 5 744
-3980: 9 8 2 3 6 1 76 5 3 2 8 4
-2057374: 33 7 565 11 34
-126929: 6 3 9 7 927
-121779: 555 53 3 6 87 34 165 9
-41269: 157 5 52 448 3
-1095433: 316 1 33 493 32
-759: 2 1 9 69
-9590393: 304 15 6 798 3
-8186291236: 818 62 91 238 1
-156397000: 6 9 6 4 6 2 1 783 88 250
-140024803776: 2 488 505 54 807 4 88
-253929288: 552 46 9 28 9
-53475506: 562 58 32 95 6
-316964278497: 566 56 427 8 48 1 4
-1987: 9 3 73 8 8
-18061344: 6 330 1 96 87 9 6 7 6 35
-1093604: 6 269 913 7 92
-52749645553: 1 975 6 4 2 8 7 172 7 9 7
-55123: 5 689 2 8 3
-1018: 9 43 66 7
-45663: 34 4 12 35 28
-221867989: 913 27 1 99 3 797 9
-17413621878: 290 227 6 13 5 78
-39627552: 81 91 896 169 6 33 7 2
-623627490: 3 7 260 3 1 27 3 6 470
-210555237: 1 1 6 319 2 2 2 3 6 23 5
-111797277: 5 837 4 4 1 981 8 5 9 8 6
-4489668616: 3 663 30 9 4 6 22 57 7
-70992: 61 648 9 4
-10136086086: 8 515 36 246 7 5 411
-125553456: 415 19 84 9 4
-432099851242: 3 9 8 9 5 998 5 1 17 7 2 1
-1197740: 57 15 53 14
-9841: 3 51 2 6 2 24 52 6 104 7
-1095765: 251 9 1 2 7 32 4 5 6 1 4 5
-1418604: 153 843 4 1 487 954 6
-395781: 40 38 98 4 54
-19638635: 2 2 4 71 49 722 9 226
-4122: 1 2 963 4 225
-259532427: 59 7 1 434 426
-9280106: 256 725 3 5 86 5
-36067626950: 89 15 83 8 27 953
-1320: 9 59 553 146 93
-144106119080: 98 94 971 6 15 77
-14557125649733: 8 1 8 554 811 986 8 7 5
-1677997: 1 26 63 1 385 4 9 94
-336386821: 10 5 32 550 73 187 7
-59025094863: 4 4 36 287 39 6 779 3
-96589446: 7 91 675 9 818 5 3 2
-7317283837: 4 60 18 67 114 34 3
-90806: 7 2 7 7 92
-96480541939: 459 431 15 21 21
-873: 33 1 4 23
-1695206159: 221 851 285 2 4 90
-6130571: 2 61 61 5 68
-39372208: 1 8 8 7 99 20 35 4 7 8
-18612096: 4 112 16 94 5 5 60 96
-6853511: 85 51 587 75 1 9
-266310616: 40 1 8 9 8 66 6 1 6
-9981672331684: 53 4 4 68 76 4 981 7 4
-44867478: 4 4 8 649 9 8 4 92 7 379
-76841: 18 4 754 93 23
-43763: 429 83 24 45 653 58
-362472323020: 891 1 3 53 17 9 460 4 5
-46448727: 7 1 5 3 9 6 81 8 6 6 67 6
-2461730: 82 3 17 32
-5284: 63 20 5 60 6
-116: 55 54 7
-939960134464: 71 752 681 95 7 1 131
-953880648: 2 362 8 67 83 503
-2976255212785: 87 4 3 30 95 7 3 2 786
-62433231224: 52 43 2 292 2 5 698 1
-2687832: 68 77 97 252 2
-9445356541: 5 8 37 30 5 5 163 41 9 1
-340425769: 12 73 89 45 772
-1097: 4 8 54 459 1 2 46
-5042559329287: 9 7 2 2 5 5 796 8 8 81 2 7
-10847196: 69 6 4 3 898 4 2 4 9 9 30
-18516887: 661 2 91 28 740
-391114505: 7 3 19 3 5 2 5 386 7 15 5
-28751890: 7 5 4 53 62 11 85 88 7 3
-3775884100: 65 101 4 50 58
-4291172291: 395 831 5 246 7 91
-4553304576: 93 765 376 8 64
-262: 4 56 66 88 48
-5949008: 586 8 80 320 691
-21516: 94 1 54 2 498 2
-499402575: 975 156 6 616 6 3 545
-15886516: 6 23 8 45 914 314
-566023992: 3 45 3 6 119 14 35
-1788409: 57 663 31 854 2
-761967136: 17 46 60 884 904
-1348913535713: 47 287 13 535 714
-1793: 4 424 1 2 86 8
-1541149: 570 52 7 35 7 5 1 89 7
-879: 26 357 496
-19725338: 52 7 995 48 7 458
-1407751082089: 9 5 7 7 91 9 138 7 5 49 8
-119: 2 3 2 53 6
-967365: 967 3 66
-75140232: 75 139 743 457 29
-2095326: 54 715 57 6 3 1 806 3 9
-8469341066: 4 3 66 4 8 8 66 2 8 907 9
-4527087: 2 20 17 93 9 30 86
-14688: 27 1 9 5 5 3 4 6 9 5 94 14
-764545: 2 8 1 4 37 66 594 9 67
-10374592350: 110 351 17 94 349
-93138130236: 7 225 5 2 8 5 4 1 29 591
-6309501: 381 92 5 2 4 2 6 9 1 6 1 8
-144372247: 67 59 267 8 7
-32204: 8 333 63 78 7 8 592 85
-12794630625: 395 789 6 88 48 5 5 45
-147538: 92 47 8 535 4
-341844: 506 7 7 1 6 2 8 8 1 976 3
-113702: 9 76 166 149 8
-1523526690: 3 91 5 41 6 140 93 6 90
-4914429: 44 5 6 51 4 438 69
-20976: 36 8 64 1 40 3 9 46
-645125222: 5 15 7 8 20 7 4 4 8 58 9 2
-18774236: 474 50 35 7 358
-65485435: 8 5 7 6 8 7 5 517 4 69 9 3
-43201942787: 54 686 79 278 7
-2213: 579 651 2 418 2 8 553
-1925205028706: 7 3 7 3 4 4 3 887 8 654 6
-2196748: 5 5 122 311 8 2 6 487 4
-7402752: 9 33 72 8 3 102
-135500: 767 969 1 78 14
-8821063171: 94 209 449 9 173
-16207705800: 7 2 130 22 834 755
-67661: 3 18 641 14 60
-2704169: 3 1 1 51 3 1 2 72 6 9 283
-4658180909: 36 5 4 6 566 5 81 8 8 80
-7562497803: 75 549 75 977 2 3 78
-108818150: 141 45 49 1 4 2 25 7
-55201563135: 51 4 194 7 563 138
-17: 9 4 4
-280065690098: 64 1 9 7 41 9 49 84 775
-250635474: 7 574 770 7 393 5 9 1 9
-22949642: 75 995 383 5 7 8 2
-3338140: 7 3 26 963 1 2 9 4 1 370
-8629264656: 63 89 45 2 68 342
-17658484538: 966 914 1 227 20
-990156: 2 987 973 178 4
-5098320: 49 6 67 4 6 5 9 776 5 9
-83260: 802 8 84 11 92
-91848: 288 47 9 267 1
-113480: 67 9 34 3 480
-187926960: 5 82 8 310 871
-928486: 871 2 95 35 2 61 871
-38547: 494 78 1 8 6
-350258: 8 4 99 5 8 4 9 6 6 78 8 2
-32403520: 9 8 4 16 111 1 8 3 5 40 8
-64168545: 2 14 13 1 4 1 228
-4901: 5 1 96 5
-32185440: 61 3 744 31 40 4 7
-1791603: 179 91 867 1 95
-312242: 77 3 7 6 8 5 3
-1968834563: 948 59 1 5 65 88 4
-96959: 358 27 22 7 9
-10843626757: 299 57 87 532 633
-188756400: 1 3 586 31 49 805
-9878383: 15 462 879 5 253
-223460: 7 446 4 300 295 145
-6522815: 24 4 3 1 5 1 6 349 5
-319259894: 57 56 595 3 97
-16356414611: 4 9 625 5 345 5 3 3 1 2 2
-10925466561: 79 52 834 66 561
-210847: 2 5 2 154 83 8 63 7 91
-9771484818: 2 64 371 9 44 21 5 4
-1517: 7 1 9 701 797
-366017164: 14 22 60 17 161
-705406145: 2 278 3 57 11 9 7 3 7 39
-4320: 8 54 2
-1136938409: 1 6 411 4 794 7 619 71
-26151: 4 5 645 714 4 75
-12141509451: 64 3 1 7 22 3 7 9 1 4 6 7
-6643619611: 277 72 54 4 443
-37988330: 51 8 67 310 31
-344528998: 353 976 989 5 5
-86260: 4 3 85 7 705 25 3 2 61 6
-207821952: 9 3 442 933 7 52 9 32
-68857957: 1 377 50 85 666 44 5
-22549414: 3 5 7 517 6 7 9 10 403 8
-91219296896: 4 91 1 362 784 883
-13340717: 7 9 74 601 5 8 54 3 6 4 7
-13277825: 236 6 7 98 7 4 53 25
-34904568801: 3 5 4 7 720 8 2 327 3 66
-365440507746: 35 75 291 232 91 6
-1217646: 87 7 310 1 883 3
-4542786: 4 5 114 3 78 13 2 3 74
-2487245: 76 58 2 94 6 5
-71519: 3 7 5 337 71 4 2 3 62 3 7
-367213749: 1 5 918 171 9 2 4
-394549089609: 4 5 1 977 3 727 240 4 9
-117940712: 4 897 69 46 349
-147064770258: 41 3 69 8 81 918 8 483
-10138575600: 6 50 6 474 51 233 1
-11877: 6 5 319 9 4
-10018771: 9 8 89 5 7 4 2 5 15 9 421
-4919: 328 1 817 85 4
-1399115897647: 4 62 6 7 7 42 8 60 54 9
-702776: 8 7 483 97 8
-63862: 3 71 863
-12256176: 8 5 3 8 6 834 126 3 4 6 8
-33442856: 899 186 25 7 8
-3609624055: 7 7 8 9 9 5 2 9 704 701 3
-1730553335: 24 2 9 505 67 66 333 4
-223848: 5 471 5 6 588 3 1 1 29 8
-410887: 6 36 6 369 61 55
-71437850: 34 7 7 9 1 74 8 9 1 2 8 2
-22671: 27 793 1 336 924
-189519651: 920 89 21 5 98
-35790480396: 2 4 5 6 9 7 810 1 8 1 395
-1938893491925: 8 9 153 4 4 2 10 59 88 3
-15984845871: 465 289 53 11 4 6 4 3 1
-4178671207: 7 9 1 8 73 26 2 86 5 8 5 7
-583945973953: 8 8 8 923 71 2 5 44 33
-4632752: 734 7 5 621 9 4
-26197367269: 1 2 803 328 98 7 26 4 3
-538104: 267 2 20 94 4 1 7
-619952448: 65 9 4 6 83 4 76 10 7 10
-517041: 752 8 85 22 8 8
-97747: 3 47 630 13 34
-6739294: 91 23 88 309 5
-1637457526: 33 660 5 5 11 27 3 53
-7369541154800: 602 9 2 535 979 649 4
-3452722: 60 9 7 56 31 23
-106641041: 1 32 7 2 9 3 3 6 204 7 5 9
-12139856: 8 6 42 74 279 4 1
-8144806: 8 9 9 4 8 8 18 8 9 2 80 6
-20728968: 285 7 3 8 6 3 9 6 85 1 4 6
-824529024: 759 4 6 328 138
-24066223: 9 14 191 22 10
-15546301: 8 18 18 190 881
-2250828: 7 4 958 62 2 8 2 44 2 3
-75450608: 24 2 969 8 1 150 501 8
-879206487920: 794 3 391 805 944
-54038971: 540 38 75 21 9 2
-171363: 1 91 58 3 85 8
-885: 5 1 29 32 5
-139205608: 582 436 823 774 2 83
-39041232: 4 9 4 4 94 8 5 1 610 5 1 2
-828: 263 1 3 24 12
-3639213: 255 2 4 4 5 178
-962: 3 1 8 938 12
-925: 21 4 1 8 6 68
-5612850981: 8 8 1 76 1 9 63 88 87 45
-402040748: 5 874 92 672 76
-6343584591: 90 7 2 1 8 388 9 845 8 9
-36893: 2 91 6 733 246 3 8 5
-239916393: 7 1 765 424 2 449 731
-6784649: 643 9 5 259 647
-1834: 83 826 7 1 2
-19808: 95 2 5 116 1 5 5 2 5 4 4 2
-199130: 497 9 2 390 981 24 1 4
-218513331718: 25 571 6 91 9 6 39 5 17
-10024: 939 9 7 40 6 66
-12273646: 1 414 6 2 8 2 3 1 6 9 7 22
-13043384735: 3 2 570 56 9 4 5 5 3 644
-5929930318: 474 255 2 39 28 8 3 26
-3105664675: 8 1 701 4 2 4 2 4 674
-33549: 799 867 4 5 40 1 5
-19491: 13 2 46 3 5 3 970 6 7 6
-7281282: 49 23 8 1 2 83
-2941225352: 6 88 2 985 1 8 648 1 6 2
-743842143: 3 46 5 2 9 1 9 592 1 6 50
-3770861494685: 3 9 639 5 18 7 755 6 6 5
-230900220: 49 645 6 401 9 93 135
-139021: 27 5 3 91 8 9 96
-2339418: 687 798 5 35 9 545
-17623875: 6 3 160 2 3 45 4 4 252 3
-7093008549: 1 737 8 4 4 9 9 6 4 5 49 3
-2161990244728: 2 2 56 1 9 803 9 473 30
-4233496995055: 1 9 79 569 70 850 6 9
-49611735: 3 8 398 8 9 5 68 4 11 2 2
-314315: 4 479 11 2 7
-15718188186: 260 6 9 2 818 8 1 81 2
-241: 24 1 1
-3010: 6 48 5 4 78
-1135: 1 4 281 2 9
-81773: 40 5 43 18
-30460941: 78 7 6 9 2 815 8 3 6 1 2 2
-35705061092: 6 9 39 8 2 816 6 7 5 6 9 3
-25386661278: 4 6 5 7 82 11 64 1 2 7 5
-1759980508: 33 68 323 9 386 5
-9285284808: 519 9 943 31 963 68
-825411626: 85 6 98 359 902 9 46
-181828: 27 55 66
-3339156: 31 1 6 78 145 158
-1828013722: 5 3 2 4 9 4 42 2 1 3 9 260
-14583391061: 46 2 40 35 35 83 5 61
-11615: 1 5 54 196 50
-125065785: 9 770 2 1 52 2 8 7 4 4 7 6
-3838169: 8 1 70 7 47 922 2 8 42
-757453: 23 392 84 104 5
-25565938: 4 2 9 536 563 1 464
-31650181: 380 2 8 52 8 69 5 206
-1550195111: 7 14 2 210 5 13 6 510 9
-2288: 4 6 8 848 79 996
-10906: 2 2 4 3 574
-1857247825: 1 5 1 3 3 3 4 7 993 6 34
-519606584: 591 91 478 3 7 456 8 7
-2387585200: 909 1 445 44 134
-240706: 5 48 706
-69652758240: 4 4 95 74 9 3 2 3 5 9 4 8
-1708581168: 515 1 15 667 783 28
-3529: 6 47 8 294 1 9 951 5 8 6
-2325425: 579 6 8 95 487
-9741670662: 1 4 7 3 42 2 9 9 2 4 773 5
-38772: 75 62 188 8 68
-6401764: 2 4 367 34 7 66
-155361598651: 3 9 65 8 5 8 142 6 7 8 9 1
-77114165613: 114 1 84 821 7 2 7 1 9 2
-129739783188: 21 4 5 7 96 1 8 1 2 2 317
-5352096010: 985 566 96 1 2
-83015410: 3 734 29 65 5 2
-13959262: 93 86 951 82 484
-131105147: 69 190 49 18 232
-80465240: 2 7 48 277 19 2 34 7
-4187717: 760 9 8 544 6 1
-66632: 8 2 9 4 4 4 5 764 5 1 40 8
-16701: 12 9 3 56 2 50
-476608899: 2 7 36 3 57 6 509 125 4
-65111177648: 9 41 1 1 3 7 3 6 2 4 7 48
-3764967: 6 105 26 44 8 8 75 76
-249938: 4 2 5 6 6 8 5 4 58 8 9 984
-12144922: 487 1 41 23 913 9
-55495023: 8 867 87 8 8
-2533544483: 8 6 4 776 6 8 9 5 3 2 84
-49882405: 3 9 20 7 5 8 4 7 120 2 8
-531821285: 9 413 6 356 59 8
-982: 5 1 3 423 550
-2035142: 437 388 2 71 7 6 2
-251946: 9 3 5 8 185 5 708
-2889: 32 1 9
-38035751: 425 6 25 5 706
-1706128499: 47 363 284 97 1
-15084993: 21 5 71 2 106 21 77
-115693001: 2 5 2 151 25 6 5 192 2 7
-4116653112: 5 48 887 75 6 15
-972753516650: 979 92 1 77 3 4 9 59 6 1
-3036153: 416 4 99 650 9
-1451700796: 211 688 47 160 99
-541867381: 451 556 12 70 108
-10564977526: 6 7 1 1 3 2 29 31 4 505 5
-196219884: 96 1 66 203 86
-283035968424: 6 83 97 1 99 982 4 53 8
-326432: 3 522 19 5 5 2 6 1 6 2 8 2
-2612707589: 5 9 8 1 9 7 86 1 3 94 7 7
-43451622: 567 26 650 3 941 6
-5630: 58 503 1 2 8
-4309231498: 85 178 7 190 375 84
-111142473: 623 9 479 42 3 7 6 7 35
-7433090320: 387 14 24 29 80 3
-6215287915: 9 417 102 9 66
-135649560682: 90 4 33 15 55 5 679
-50611810415: 2 2 2 178 87 985 8 79
-4899685: 78 86 44 97 7 18
-35154763576: 2 39 84 637 7 7 1 5 74
-365264770: 913 4 64 186 584
-8019: 84 798 5 4 9
-2508: 1 24 83 389 60 2 65 1
-5458136777: 53 6 9 81 3 6 2 5 5 522
-2757995051: 29 951 948 31 222
-764140: 7 6 413 7
-3995924243581: 648 689 560 325 11 6
-154058630934: 303 504 846 1 28 61 6
-1427638: 8 13 21 593 632 5
-21098550233: 4 1 1 69 824 65 45 98 6
-178: 50 46 82
-119341: 45 57 78 15
-1001625: 6 6 2 821 2
-107025: 78 28 9 64 61
-6827328: 346 3 7 4 930 21 45 3
-747730884: 29 96 954 65 4 2 8 2
-243569365: 3 9 1 6 4 3 8 443 43 76 6
-1359330: 7 97 298 2 735
-39346886641: 2 88 8 41 3 55 1 6 659 1
-455625: 90 7 5 78 234 861 9
-601917984: 5 55 1 8 3 8 6 1 973 3 3 5
-71009287: 2 4 8 628 684 4 4 9 4
-214: 2 6 2 7 4
-2342725: 6 12 89 14 43
-25959024: 4 7 187 1 308 7 9 4 1 7 8
-972061: 4 8 70 80 84 9 383 4
-2178012557: 9 3 234 18 12 5 39 6 12
-3915: 7 35 9 6 45 46 7 20 570
-26750623: 2 67 1 50 626
-1121087: 266 1 753 17 11
-3795624: 570 6 156 516 42
-967753: 5 35 395 1 14
-31443545952: 4 18 871 987 508
-9191024368: 7 54 3 96 43 5 2 3 47
-18400: 34 5 488 263 1 6 3 23 1
-509203: 1 1 61 7 1 9 118 3 3 5 3
-174801132: 30 1 1 6 732 80 65 8 99
-211965600: 2 5 814 7 62 60
-3173091525: 998 2 8 613 7 41 9 1 1 9
-169407529: 46 7 906 7 252 2 22 4
-5331: 4 4 9 37 1
-21980222: 3 133 6 988 2 41 7 1 7
-449553702: 405 111 282 88 2
-2942: 5 15 8 1 18 62
-2320553257: 91 51 5 53 156 9 91
-9146342: 197 8 1 4 92 441 5
-966594375: 9 840 5 5 6 4 1 5 757 5 3
-4780203738: 6 7 3 17 372 1 9 7 3 857
-277037610569: 3 6 8 19 4 4 6 8 9 710 67
-9836: 7 1 41 2 16 1 652
-200552837873474: 236 780 21 847 3 47 4
-1225: 3 2 7 7 901 3 9 26 35 6
-138: 5 6 108
-48726878940: 5 4 140 9 4 3 8 9 8 3 6 40
-1072958: 27 1 9 11 158 379 9
-14700: 18 3 7 525
-27192423: 74 9 9 6 3 7 6 3 18 2 36 3
-973: 8 5 1 7 1 61
-4767602: 970 6 4 305 106 7 35 6
-5699955: 8 14 47 70 41 956
-4799016: 6 9 5 7 2 8 5 2 59 514 8 9
-1104789: 211 843 50 74 2 47
-3465098: 3 4 487 1 2 385 1 5 9 44
-1092564: 455 2 1 8 20 3
-4046781265: 418 4 5 92 1 1 263
-9213: 85 98 3 871 9
-19183: 4 6 1 4 3 1 19 133 6 4 1 6
-3057: 913 3 317 1
-308423: 64 61 79 1 7
-4532250239: 4 12 8 25 421 6 56 72
-259228473176: 8 49 77 22 91 3 1 7 9
-53531868: 910 4 15 49 36 6 8 9
-3170: 633 5 5
-7000826121: 9 5 6 3 69 84 96 233 9
-26296280: 377 12 69 87 8 704
-944: 37 8 39 1 859
-416601374947: 4 67 9 7 472 493 2 3 3 6
-87295346: 2 8 9 68 2 75 9 9 4 9 3 9
-88964084: 282 9 598 640 83
-26775736: 6 6 93 934 4
-30545: 973 5 1 2 46 2 343 9 3 5
-96136910: 93 1 2 8 8 97 1 1 9 1 890
-37564937: 375 64 861 1 77
-3400: 7 17 362 84 480 184 3
-6800: 9 9 4 20 4
-351249746890: 48 38 15 11 862 66
-7882: 1 16 26 731 6 1 3 2 8 40
-17835253284: 243 651 732 8 3
-2210544040: 730 1 3 5 4 6 28 12 5 8 5
-122544: 6 851 8 3
-897030320: 6 4 7 2 98 4 867 165 3 1
-12088: 69 387 92 22 32 1
-278: 94 8 94 2 4 76
-1349040: 84 78 9 2 3 4 1 77 1
-1909: 702 784 6 412 5
-510079: 7 9 4 28 72 77 2 27
-434835: 7 62 1 4 69 3
-30991593633: 85 6 302 13 67 77 39
-3289538589: 77 712 6 9 85 88
-6121549: 50 9 5 795 385 15 3 5
-4979436: 13 5 4 7 3 13 6 2 8 8 3 68
-129724777: 3 12 1 7 2 87 47 59 5 4
-68892363: 6 897 5 74 3 1 7 5 6 2 3 3
-4255924039: 1 72 6 94 978 41 586 3
-70979251824: 63 3 50 8 1 152 779 78
-1308486: 997 7 783 939 4 2 1 1 6
-2493632464308: 457 659 3 41 1 4 207
-348724: 34 8 722 1
-99166237: 984 7 64 2 238
-285197: 165 3 781 700 91
-9295: 53 3 57 43 7
-572564: 51 4 2 2 564
-325498150: 171 19 5 981 47
-46795: 43 1 1 2 793
-46340060: 4 5 1 3 332 4 2 8 618 1
-147087: 59 4 876 8 6 1
-4611297686: 3 34 91 2 6 69 6 85
-3862158312: 79 8 9 97 4 4 4 1 8 44 7
-789742859: 2 572 8 6 4 15 862 25 9
-579661523530: 2 5 41 29 132 211 91
-574: 9 4 6 4 7
-70184473660: 3 68 8 643 58 807 11 4
-70743726: 8 22 9 193 382
-2837431380: 2 89 49 63 3 3 4 707 1
-4458906: 44 582 4 9 656
-171138967: 1 171 13 1 7 960 7
-38732: 3 8 7 3 5
-931380691: 80 59 67 806 92
-3906490490: 2 42 1 6 21 1 96 778 2
-12218522: 2 6 21 5 4 31 2 1
-101954797: 472 6 9 692 4 4 6 1 6 14
-305743: 70 49 5 89 28
-1327567128: 1 15 189 7 1 43 82
-13548879: 6 9 8 413 47 2
-1815476055012: 9 5 637 8 681 3 7 3 3 1 2
-7297: 65 82 748 688 531
-35204: 9 10 64 6 644
-716101542: 928 505 28 9 70 9 778
-43859: 146 55 741 5 2
-597783034238: 9 2 68 866 49 190 8
-63576: 5 7 92 54 12
-508211: 2 30 60 3 88 444 887
-809: 9 501 2 7 290
-54153: 5 32 332 981 34 18
-947723581: 43 29 76 3 581
-118147: 12 7 97 7 5 1 7 911 479
-40606820: 96 6 4 7 4 2 68 6 6 74
-29223272479321: 8 654 7 923 8 40 603
-276032: 16 7 30 4 34
-78046230: 47 3 75 56 22
-553350: 6 15 80 178 75
-3735: 5 64 7 7 535 3 1 3 525
-2771237635: 8 880 2 17 39 2 4 53 6 6
-1677983: 899 9 7 8 33
-1575693: 19 4 2 962 8
-171136: 5 267 2 4 2 32
-1255703222: 22 63 97 3 13 934
-455805632: 6 7 203 8 2 9 6 5 65 5 6 5
-109920: 35 3 209 5 195
-906417640: 58 1 5 23 2 307 1 40
-25890834326636: 1 7 2 896 109 7 6 6 5 9 4
-403244184917: 2 576 5 6 1 312 13 7 2 5
-3564175: 48 74 8 2 7 6 90 6 3 815
-1558050560: 3 2 1 2 231 8 5 4 926 44
-89400308: 5 1 401 8 421 354 217
-12147: 8 5 9 84 8 37 7 618 14
-6656: 2 51 5 81 776
-191770117: 6 627 1 8 1 91 5 8 5 70 7
-36606668968: 22 282 52 59 965
-44391606965: 98 1 59 760 6 887 81
-22680: 5 52 18 6 30
-9882851925: 987 3 5 93 51 75 17 3
-83482: 345 6 2 774 7 8 62 7
-1514: 4 35 3 7 199
-63054605: 13 5 819 958
-62283458186154: 77 1 837 643 801 954
-63516: 31 42 55 6 1 67 316
-33244662713: 71 7 6 786 79 85
-126031: 3 6 7 4 59 6 5 6 4 4 147 1
-15370: 71 82 2 5 44
-214042233972: 73 9 4 2 9 1 9 2 6 8 93 4
-100495410: 5 578 3 5 4 6 41 882 6 5
-989: 8 92 95
-177497320: 9 4 4 9 8 109 1 2 7 2 605
-38159834: 1 7 17 10 3 5 9 932 3 6 8
-3692749373496: 761 8 82 30 7 8 19 8 57
-54685213: 42 4 235 1 2 45 48 55 9
-"#;
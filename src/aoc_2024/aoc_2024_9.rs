@@ -1,256 +1,550 @@
-// https://adventofcode.com/2024/day/7
+// https://adventofcode.com/2024/day/9
 
 // assumptions/constraints:
 // 1. there are no 0 block size files in our input. We confirm this by making sure the input has no '00' substring
-// 2. because the file size is only one digit, 9 is the max size of digit.
+// 2. sizes are one digit each in the puzzle's format, but a disk map can also list sizes of any width
+//    separated by whitespace, so nothing below assumes a largest size.
 
 // First thought approach:
 // unfold the run length encoding type input, then have two pointers, one starting at left and one starting at rightmost non-empty space. Left pointer traverses until it hits an empty space, then takes from the right pointer, and right pointer moves left until it hits a non empty space.
 
 // Alternative approach, keep the run length encoding, with left pointer adding as you go. Right pointer has some inner logic for when it's within a contiguous block
 
-use std::{cmp::Reverse, collections::BinaryHeap, mem::swap};
+use std::{cmp::Reverse, fmt};
 
 use itertools::Itertools;
 
-#[derive(Debug, PartialEq, Eq)]
+use crate::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Block {
     Id(usize),
     Empty,
 }
 
-fn process_input_simple(input: &str) -> Vec<Block> {
-    input
-        .trim()
-        .chars()
-        .enumerate()
-        .map(|(idx, c)| {
-            if idx % 2 == 1 {
-                (0..(c.to_digit(10).unwrap() as usize))
-                    .map(|_| Block::Empty)
-                    .collect_vec()
-            } else {
-                (0..(c.to_digit(10).unwrap() as usize))
-                    .map(|_| Block::Id(idx / 2))
-                    .collect_vec()
-            }
+// Unfolds the run length encoding into one entry per block.
+fn expand_runs(disk: &[BlockRun]) -> Vec<Block> {
+    disk.iter()
+        .flat_map(|block| match *block {
+            BlockRun::File(length, id) => (0..length).map(|_| Block::Id(id)).collect_vec(),
+            BlockRun::Empty(length) => (0..length).map(|_| Block::Empty).collect_vec(),
         })
-        .flatten()
         .collect_vec()
 }
 
-fn solve_simple(input: &str) -> usize {
-    let disk = process_input_simple(input);
-    let mut left_idx: usize = 0;
-    let mut right_idx: usize = disk.len() - 1;
-    let mut checksum: usize = 0;
-    while left_idx <= right_idx {
-        // if right_idx isn't pointing to a set block, move left until it does.
-        while disk[right_idx] == Block::Empty {
-            right_idx = right_idx - 1
+// A run of blocks that all belong to the same file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct File {
+    id: Id,
+    offset: usize,
+    length: Length,
+}
+
+// The disk laid out block by block, so compaction really moves blocks around and every step can be
+// looked at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disk {
+    blocks: Vec<Block>,
+}
+
+impl Disk {
+    fn new(runs: &[BlockRun]) -> Disk {
+        Disk {
+            blocks: expand_runs(runs),
+        }
+    }
+
+    fn checksum(&self) -> usize {
+        self.blocks
+            .iter()
+            .enumerate()
+            .map(|(offset, block)| match block {
+                Block::Id(id) => offset * id,
+                Block::Empty => 0,
+            })
+            .sum()
+    }
+
+    // Moves the rightmost file block into the leftmost free block, returning false once every free
+    // block is to the right of every file block. Only the tests step like this, since it looks for
+    // both blocks from scratch each time.
+    #[cfg(test)]
+    fn move_block(&mut self) -> bool {
+        let (mut left, mut right) = (0, self.blocks.len());
+        self.move_block_between(&mut left, &mut right)
+    }
+
+    // move_block, but only looking from left up to right, and leaving them around the next move so
+    // compacting the whole disk only passes over it once.
+    fn move_block_between(&mut self, left: &mut usize, right: &mut usize) -> bool {
+        while *left < *right && self.blocks[*left] != Block::Empty {
+            *left += 1;
+        }
+        while *left < *right && self.blocks[*right - 1] == Block::Empty {
+            *right -= 1;
         }
-        // for every iteration, either count the current set block, or "move" the rightmost set block
-        match disk[left_idx] {
-            Block::Id(id) => checksum = checksum + left_idx * id,
-            Block::Empty => {
-                let Block::Id(right_id) = disk[right_idx] else {
-                    panic!("right_idx is pointing to an empty block.")
-                };
-                checksum = checksum + left_idx * right_id;
-                // We shouldn't actually have to swap since we'll stop counting before we get to anything the right_idx is touching.
-                right_idx = right_idx - 1;
+        if *left >= *right {
+            return false;
+        }
+        self.blocks.swap(*left, *right - 1);
+        true
+    }
+
+    fn compact_blocks(&mut self) {
+        let (mut left, mut right) = (0, self.blocks.len());
+        while self.move_block_between(&mut left, &mut right) {}
+    }
+
+    // Every run of blocks belonging to one file, from the start of the disk.
+    fn files(&self) -> Vec<File> {
+        let mut files: Vec<File> = Vec::new();
+        for (offset, block) in self.blocks.iter().enumerate() {
+            let Block::Id(id) = *block else {
+                continue;
+            };
+            match files.last_mut() {
+                Some(file) if file.id == id && file.offset + file.length == offset => {
+                    file.length += 1
+                }
+                _ => files.push(File {
+                    id,
+                    offset,
+                    length: 1,
+                }),
+            }
+        }
+        files
+    }
+
+    // Moves a whole file into the leftmost free space before it that fits it, if there is one.
+    fn move_file(&mut self, file: File, free: &mut FreeSpace) -> bool {
+        let Some(offset) = free.take(file.length, file.offset) else {
+            return false;
+        };
+        for i in 0..file.length {
+            self.blocks.swap(offset + i, file.offset + i);
+        }
+        true
+    }
+
+    // Tries to move each file once, starting from the highest id.
+    fn compact_files(&mut self) {
+        let mut free = FreeSpace::new(self);
+        let mut files = self.files();
+        files.sort_by_key(|file| Reverse(file.id));
+        for file in files {
+            self.move_file(file, &mut free);
+        }
+    }
+}
+
+// The puzzle's notation, with each block showing its file id or a . when free. Once ids run past
+// one digit every block is padded to the widest id and separated by a space, so there's still one
+// cell per block.
+impl fmt::Display for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widest = self.blocks.iter().fold(0, |widest, block| match block {
+            Block::Id(id) => widest.max(*id),
+            Block::Empty => widest,
+        });
+        let width = widest.to_string().len();
+        for (i, block) in self.blocks.iter().enumerate() {
+            if i > 0 && width > 1 {
+                f.write_str(" ")?;
+            }
+            match block {
+                Block::Id(id) => write!(f, "{:>width$}", id)?,
+                Block::Empty => write!(f, "{:>width$}", ".")?,
             }
         }
-        // always move left_idx right by one
-        left_idx = left_idx + 1
+        Ok(())
     }
-    checksum
 }
 
 type Length = usize;
 type Id = usize;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
-enum BlockRun {
+pub enum BlockRun {
     File(Length, Id),
     Empty(Length),
 }
 
-fn process_input_complex(input: &str) -> Vec<BlockRun> {
-    input
-        .trim()
-        .chars()
-        .enumerate()
-        .map(|(idx, c)| {
-            if idx % 2 == 1 {
-                BlockRun::Empty(c.to_digit(10).unwrap() as usize)
-            } else {
-                BlockRun::File(c.to_digit(10).unwrap() as usize, idx / 2)
+// Reads the puzzle's one digit per size, or whitespace separated sizes of any width.
+fn process_input(input: &str) -> Result<Vec<BlockRun>, ParseError> {
+    let disk_map = input.trim();
+    let spaced = disk_map.contains(char::is_whitespace);
+    let sizes: Vec<&str> = if spaced {
+        disk_map.split_whitespace().collect()
+    } else {
+        disk_map
+            .char_indices()
+            .map(|(offset, c)| &disk_map[offset..offset + c.len_utf8()])
+            .collect()
+    };
+    let mut runs = Vec::new();
+    for (idx, size) in sizes.into_iter().enumerate() {
+        let length: usize = if spaced {
+            parse::number(input, size)?
+        } else {
+            size.parse()
+                .map_err(|_| ParseError::at(input, size, "expected a digit"))?
+        };
+        if idx % 2 == 1 {
+            if length > 0 {
+                runs.push(BlockRun::Empty(length));
             }
-        })
-        .filter(|block| match block {
-            &BlockRun::Empty(length) => length > 0,
-            BlockRun::File(_, _) => true,
-        })
-        .collect_vec()
-}
-
-// If there is a free space to the left of the given offset that will fit a file at the given size, return the index of that free space. Otherwise return None
-fn fit_file(
-    offset: usize,
-    file_size: usize,
-    free_chunks: &Vec<BinaryHeap<Reverse<usize>>>,
-) -> Option<usize> {
-    // idx refers to the index in free_chunks, which also corresponds to the (size - 1) of the free space.
-    free_chunks
-        .iter()
-        .enumerate()
-        .filter(|&(idx, _)| idx >= file_size - 1)
-        .map(|(idx, heap)| {
-            heap.iter().find_map(|&Reverse(free_space_offset)| {
-                if free_space_offset < offset {
-                    Some((idx, free_space_offset))
-                } else {
-                    None
-                }
-            })
-        })
-        .filter_map(|x| match x {
-            Some((idx, _)) => Some(idx),
-            None => None,
-        })
-        .min()
+        } else {
+            runs.push(BlockRun::File(length, idx / 2));
+        }
+    }
+    Ok(runs)
 }
 
-fn calculate_checksum(offset: usize, size: usize, id: usize) -> usize {
-    (0..size).fold(0, |sum, i| sum + (offset + i) * id)
+// The free spans on a disk in offset order, with a segment tree over their sizes so the leftmost span
+// that fits a file is found by walking down from the root. Spans only ever shrink from the front,
+// since a file only moves left and the space it leaves is to the right of every file still to move.
+struct FreeSpace {
+    // Offset and size of each span.
+    spans: Vec<(usize, usize)>,
+    // tree[1] is the largest span and each tree[i] the larger of tree[2 * i] and tree[2 * i + 1],
+    // down to the spans themselves from tree[leaves] on.
+    tree: Vec<usize>,
+    leaves: usize,
 }
 
-fn solve_complex(input: &str) -> usize {
-    let disk = process_input_complex(input);
-    // calculate the checksum for the disk without any modifications.
-    let (mut checksum, _) = disk
-        .iter()
-        .fold((0, 0), |(sum, offset), block| match block {
-            &BlockRun::File(length, id) => (
-                sum + calculate_checksum(offset, length, id),
-                offset + length,
-            ),
-            &BlockRun::Empty(length) => (sum, offset + length),
-        });
-
-    // make a list of all files and sizes, in descending order, from rightmost to leftmost start position
-    let files: Vec<(usize, BlockRun)> = disk
-        .iter()
-        .scan(0, |offset: &mut usize, block| match block {
-            &BlockRun::File(length, id) => {
-                let old_offset = *offset;
-                *offset = *offset + length;
-                Some((old_offset, *block))
-            }
-            &BlockRun::Empty(length) => {
-                let old_offset = *offset;
-                *offset = *offset + length;
-                Some((old_offset, *block))
-            }
-        })
-        .filter(|(_, block)| matches!(*block, BlockRun::File(_, _)))
-        .collect_vec();
-    let files = files.iter().rev().collect_vec();
-    // Build a vector of 0-8 (representing sizes 1-9) to each store a binary heap of free chunks of that size.
-    let mut free_chunks = (0..9)
-        .map(|_| BinaryHeap::<Reverse<usize>>::new())
-        .collect_vec();
-    let mut offset: usize = 0;
-    for block in &disk {
-        match block {
-            &BlockRun::Empty(length) => {
-                free_chunks[length - 1].push(Reverse(offset));
-                offset = offset + length;
+impl FreeSpace {
+    fn new(disk: &Disk) -> FreeSpace {
+        let mut spans = Vec::new();
+        for (is_free, mut run) in &disk
+            .blocks
+            .iter()
+            .enumerate()
+            .chunk_by(|(_, &block)| block == Block::Empty)
+        {
+            if is_free {
+                let (offset, _) = run.next().unwrap();
+                spans.push((offset, run.count() + 1));
             }
-            &BlockRun::File(length, _) => offset = offset + length,
         }
+        FreeSpace::from_spans(spans)
     }
-    // println!("{:?}", disk);
-    // println!("{:?}", files);
-    // println!("{:?}", free_chunks);
-    println!("{:?}\n", checksum);
-
-    // for each file, check if there is a free space that will fit it.
-    // Finding the leftmost free space involves creating a list of the lowest indices of all the free chunk sizes that will fit the current file.
-    // Taking the minimum index from that list, we remove that element from the binary heap, create a new element of free_space_size - file_size with the proper index, and put that back into the vector of heaps.
-    // subtract the checksum of the moved file before the "move", then add the checksum of the file with its new offset.
-    for file in &files {
-        match file {
-            &&(offset, BlockRun::File(length, id)) => {
-                let Some(chunk_idx) = fit_file(offset, length, &free_chunks) else {
-                    continue;
-                };
-                let Some(Reverse(idx)) = free_chunks[chunk_idx].pop() else {
-                    panic!("fit_file returned an index with an empty heap inside")
-                };
-                checksum = checksum + calculate_checksum(idx, length, id)
-                    - calculate_checksum(offset, length, id);
-                if (chunk_idx + 1) - length > 0 {
-                    free_chunks[chunk_idx - length].push(Reverse(idx + length));
-                }
-                // println!("{:?}", checksum);
-                // println!(
-                //     "idx: {:?}, length: {:?}, id: {:?}, offset: {:?}",
-                //     idx, length, id, offset
-                // );
-                // println!("{:?}\n", free_chunks);
-            }
-            _ => panic!("all files should be of variant File"),
+
+    fn from_spans(spans: Vec<(usize, usize)>) -> FreeSpace {
+        let leaves = spans.len().next_power_of_two();
+        let mut tree = vec![0; 2 * leaves];
+        for (i, &(_, size)) in spans.iter().enumerate() {
+            tree[leaves + i] = size;
         }
+        for node in (1..leaves).rev() {
+            tree[node] = tree[2 * node].max(tree[2 * node + 1]);
+        }
+        FreeSpace {
+            spans,
+            tree,
+            leaves,
+        }
+    }
+
+    // The index of the leftmost span at least min_size long.
+    fn leftmost(&self, min_size: usize) -> Option<usize> {
+        if self.tree[1] < min_size {
+            return None;
+        }
+        let mut node = 1;
+        while node < self.leaves {
+            node = if self.tree[2 * node] >= min_size {
+                2 * node
+            } else {
+                2 * node + 1
+            };
+        }
+        Some(node - self.leaves)
     }
 
-    checksum
+    // Claims the leftmost space for a file of the given size, as long as it starts before offset, and
+    // returns where it starts. Whatever the file doesn't use stays free.
+    fn take(&mut self, file_size: usize, offset: usize) -> Option<usize> {
+        let i = self.leftmost(file_size)?;
+        let (start, size) = self.spans[i];
+        if start >= offset {
+            return None;
+        }
+        self.spans[i] = (start + file_size, size - file_size);
+        let mut node = self.leaves + i;
+        self.tree[node] = size - file_size;
+        while node > 1 {
+            node /= 2;
+            self.tree[node] = self.tree[2 * node].max(self.tree[2 * node + 1]);
+        }
+        Some(start)
+    }
 }
 
-pub fn main() {
-    println!("{:?}", process_input_simple(INPUT_1))
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<BlockRun>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        process_input(input)
+    }
+
+    fn part_one(&self, runs: &Self::Input) -> usize {
+        let mut disk = Disk::new(runs);
+        disk.compact_blocks();
+        disk.checksum()
+    }
+
+    fn part_two(&self, runs: &Self::Input) -> usize {
+        let mut disk = Disk::new(runs);
+        disk.compact_files();
+        disk.checksum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::{example, puzzle};
+    use crate::solution::{solve, Part};
+
+    fn disk(input: &str) -> Disk {
+        Disk::new(&Solver.parse(input).unwrap())
+    }
+
+    #[test]
+    fn render_layout() {
+        assert_eq!(disk("12345").to_string(), "0..111....22222");
+        assert_eq!(
+            disk(&example(2024, 9, 1)).to_string(),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+    }
+
+    #[test]
+    fn move_blocks_one_at_a_time() {
+        let mut disk = disk("12345");
+        let mut steps = vec![disk.to_string()];
+        while disk.move_block() {
+            steps.push(disk.to_string());
+        }
+        assert_eq!(
+            steps,
+            [
+                "0..111....22222",
+                "02.111....2222.",
+                "022111....222..",
+                "0221112...22...",
+                "02211122..2....",
+                "022111222......",
+            ]
+        );
+    }
+
+    #[test]
+    fn compact_blocks_layout() {
+        let mut disk = disk(&example(2024, 9, 1));
+        disk.compact_blocks();
+        assert_eq!(
+            disk.to_string(),
+            "0099811188827773336446555566.............."
+        );
+        assert_eq!(disk.checksum(), 1928);
+    }
+
+    #[test]
+    fn move_files_one_at_a_time() {
+        let mut disk = disk(&example(2024, 9, 1));
+        let mut free = FreeSpace::new(&disk);
+        let mut steps = Vec::new();
+        for file in disk.files().into_iter().rev() {
+            if disk.move_file(file, &mut free) {
+                steps.push(disk.to_string());
+            }
+        }
+        assert_eq!(
+            steps,
+            [
+                "0099.111...2...333.44.5555.6666.777.8888..",
+                "0099.1117772...333.44.5555.6666.....8888..",
+                "0099.111777244.333....5555.6666.....8888..",
+                "00992111777.44.333....5555.6666.....8888..",
+            ]
+        );
+        assert_eq!(disk.checksum(), 2858);
+    }
+
+    #[test]
+    fn list_files() {
+        assert_eq!(
+            disk("12345").files(),
+            [
+                File {
+                    id: 0,
+                    offset: 0,
+                    length: 1
+                },
+                File {
+                    id: 1,
+                    offset: 3,
+                    length: 3
+                },
+                File {
+                    id: 2,
+                    offset: 10,
+                    length: 5
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn take_leftmost_fitting_space() {
+        // a 5 block span at 2 and a 3 block span at 10, so the smaller span is further right.
+        let mut free = FreeSpace::from_spans(vec![(2, 5), (10, 3)]);
+        assert_eq!(free.take(2, 100), Some(2));
+        // the 3 blocks left over from the first span are still leftmost.
+        assert_eq!(free.leftmost(1).map(|i| free.spans[i]), Some((4, 3)));
+        assert_eq!(free.take(3, 100), Some(4));
+        assert_eq!(free.take(4, 100), None);
+        assert_eq!(free.take(3, 10), None);
+        assert_eq!(free.take(3, 11), Some(10));
+        assert_eq!(free.leftmost(1), None);
+    }
+
+    #[test]
+    fn no_free_space() {
+        let mut free = FreeSpace::from_spans(Vec::new());
+        assert_eq!(free.take(1, 100), None);
+    }
+
+    #[test]
+    fn parse_spaced_sizes() {
+        assert_eq!(
+            Solver.parse("12 0 3 10 1\n").unwrap(),
+            [
+                BlockRun::File(12, 0),
+                BlockRun::File(3, 1),
+                BlockRun::Empty(10),
+                BlockRun::File(1, 2),
+            ]
+        );
+        // the same disk as "12345".
+        assert_eq!(disk("1 2 3 4 5").to_string(), "0..111....22222");
+    }
+
+    #[test]
+    fn display_pads_multi_digit_ids() {
+        // files 0 to 9 with no gaps, then a free block and two blocks of file 10.
+        let disk = disk(&format!("{}1 2", "1 0 ".repeat(9) + "1 "));
+        assert_eq!(disk.to_string(), " 0  1  2  3  4  5  6  7  8  9  . 10 10");
+        assert_eq!(disk.to_string().split_whitespace().count(), 13);
+    }
+
+    #[test]
+    fn reject_bad_sizes() {
+        let error = Solver.parse("12 x 3").unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (4, "expected a number")
+        );
+        let error = Solver.parse("12x3").unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (3, "expected a digit")
+        );
+    }
+
+    // A disk map of whitespace separated sizes, from a fixed seed so it's the same every run.
+    fn synthetic_disk_map(files: usize, max_file: u64, max_free: u64) -> String {
+        let mut state: u64 = 0x2024_0009;
+        let mut next = move |max: u64| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) % (max + 1)
+        };
+        (0..files)
+            .flat_map(|_| [1 + next(max_file - 1), next(max_free)])
+            .map(|size| size.to_string())
+            .join(" ")
+    }
+
+    // Whole-file compaction by scanning the blocks for each file's space, to check the index against.
+    fn compact_files_by_scanning(disk: &mut Disk) {
+        for file in disk.files().into_iter().rev() {
+            let mut run = 0;
+            for offset in 0..file.offset {
+                if disk.blocks[offset] != Block::Empty {
+                    run = 0;
+                    continue;
+                }
+                run += 1;
+                if run == file.length {
+                    let start = offset + 1 - run;
+                    for i in 0..file.length {
+                        disk.blocks.swap(start + i, file.offset + i);
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn compact_files_matches_scanning() {
+        let start = disk(&synthetic_disk_map(1000, 20, 20));
+        let mut indexed = start.clone();
+        indexed.compact_files();
+        let mut scanned = start;
+        compact_files_by_scanning(&mut scanned);
+        assert_eq!(indexed, scanned);
+    }
+
+    #[test]
+    fn compact_million_files() {
+        let mut disk = disk(&synthetic_disk_map(1_000_000, 12, 4));
+        let before = disk.files();
+        disk.compact_files();
+        let after = disk.files();
+        // every file is still in one piece, and none of them moved right.
+        assert_eq!(after.len(), before.len());
+        let mut after = after;
+        after.sort_by_key(|file| file.id);
+        for (old, new) in before.iter().zip(&after) {
+            assert_eq!((old.id, old.length), (new.id, new.length));
+            assert!(new.offset <= old.offset);
+        }
+    }
 
     //Puzzle tests
     #[test]
     fn solve_simple_input_1() {
-        assert_eq!(solve_simple(INPUT_1), 1928);
+        assert_eq!(solve(&Solver, &example(2024, 9, 1), Part::One), 1928);
     }
 
     #[test]
     fn solve_simple_input_2() {
-        assert_eq!(solve_simple(INPUT_2), 6401092019345);
+        assert_eq!(solve(&Solver, &puzzle(2024, 9), Part::One), 6401092019345);
     }
 
     #[test]
     fn solve_complex_input_0() {
-        assert_eq!(solve_complex(INPUT_0), 2858);
+        assert_eq!(solve(&Solver, &example(2024, 9, 0), Part::Two), 132);
     }
 
     #[test]
     fn solve_complex_input_1() {
-        assert_eq!(solve_complex(INPUT_1), 2858);
+        assert_eq!(solve(&Solver, &example(2024, 9, 1), Part::Two), 2858);
     }
 
-    // #[test]
-    // fn solve_complex_input_2() {
-    //     assert_eq!(solve_complex(INPUT_2), 0);
-    // }
+    #[test]
+    fn solve_complex_input_2() {
+        assert_eq!(solve(&Solver, &puzzle(2024, 9), Part::Two), 6431472344710);
+    }
 }
-
-const INPUT_0: &str = r#"
-12345
-"#;
-
-const INPUT_1: &str = r#"
-2333133121414131402
-"#;
-
-const INPUT_2: &str = r#"
-7889985818705437406445235215557494675897197896477956638075433571904034142244995284823213398813689135711440962896562420828845124882727559461244587995344030583278125076902246579749311272513984382442219974906342354489109387361276162640381787907086827920398053362422897520233347317038617618585023854417993592782880133941691094119866977956618320419879131036875042412696195454461120673850833788955350632391194648884524134980648710336371846050793252449758484819695195305421824019604226696535775255525553289828124376205488678130935290466466568993229842353542674943734363218231225820196069403457252573697641808660317127876135907412662498672885627632407268412366503840449879527082715287143841692495224662237035978442662876483579404923497770383432259777573595434542456387125698566735601631818972149828866784755112872460815019256086682976996828371128349184171344484561361075538418387916176620978117431782233728975921807299134779701850418312183149718270848574892499109814229029846519663594403489932054239890844368833871561858105729988870693346843946792926749521667450199371197461514255958299295636465294624384785463403665816093915429757586992376945368757695403750996521775448441960441888777059878966288724917561952591808013698718608443427646677270266537646858337942975913741318169110134444346535581316484249176179185160875426106464315110753463989417586810684282638816135319702315773194568857117187132516441271478189726052747715599337179654429556457430267476681027136798964685493159444677835231396958313673178698679096921059656032381248223280847542265567544019497997358076429599423274521318647151868377582186347191221452268183567876267762828571788095625325269270308963766197348073149822572674918872641439396863582137307919179317825314916019494227298849682534658321597446232393631810776652836617522548635878774089334636515447247846877690533628458486538178496288242939825577942030589178521248675630614640973877816272445442208220622795214427346786752087176057161857906286715315243620186958402331108520313393962275793947286965779089141399584998655475702486843328493779131987627369757312199486574736165833725545528330616952472757938830918731675740719230371517619243494980316393551575223942635623813557935554281689309375893615792458216393433441528662451394642115403378467912887253733447549867444011619989192377587496608473909537407273817598888239146982929185209498314870443173911678688880518759899142362563489648584961459278912614832037647267362710838812258158425862585466137173509830397520484747836686507314122654928241319766606467224836244939115079453722619956841157896186114658281099957641664425391122317378912035531967184511118188197532641428861964801390857667651728684329151162803161366191647171669037851986685573483697383546843181891824759326152152471357926625872545777118461592768181559375195828249374319930354136176615591463598380642239473787179922802081233893133363703869999644474151438313391618779036704043221661274610333531286930427951358659933889597690657739562011265828545058151628657756887041978758444673756477314748386837461123436430718522729898853467325934835762426134824140667298412756513953105890908896492530486064638060328189448392587313201321656876885729731485581367546363936825658751678843319478945680696089813980709940806789297070194549309891702820795583628366885184431019917558672568158220601294341510102797272711791874602974109588335210581960697540669027498015771170429271874610899128554738965737617673117663645673906749355477137151352730119666223431327371722890813623146465128272889968525394258083782240644165997234966319761574857988518484728192276239254398215246946132284861674283253435258717969765242826508220214279228236375492102382379384887734951012606590226194673849716934959759497535506768463064408954195577156772947026451852306069295428225169953025728538202317199411649046319869504668611097139114229770115435543710341720482955493343162278196616614040943517352630961943946747965164969235694814847725547371501867896561588129104851594669597069992826818041206576911220989285543887438253889211334874351647206662642559408786512846255914238833517662141483552561311427535743111221338156975413251883565463799579462212597021276380805252539889757535684843588151999651883745117791654758105683487535395867793235223559229170838533674765374276507125374395676746563151868052968367639670185972159378853956364346219136908619812650864367737214128552517698888780484958767899885889409978731377775495394650963490814361667788174586628946356480527597793475237287832550983274626050804631574036302226505780589161426568648996558650691960596068281991366255225066333181612111423480833520373014699432611880726823232120896850739874589450418487527450931078197238259771479113793725165619823624993875319960819370917375189062599984846665398667907058635043882478717559286859151195148349615511947859412365873549455797587118901254407652624542608568617780517778498665393668519180468191391227862383218282877178301216996148111926273520786023817858315326321844482851827869416628304974523211413298799947876474736055826655493125253049664918562744501783188122318674906563471865806233679842779169387381831670247445989788439185809012854919772439253567102320674519593340152522503279887057687759415057437064596649838779615249155384982980811182194341982091783559182286725238586581702114624770164682372093568342164975271042831582946723988941951373113934182583949895571275833562727466915752502095903950973438785799814685232750949096499422784587891537366365374599975324659712468527166493807964508080152930179394329232477911422660904491235934608489623799735322875710106115438389201593984845327131504847695494227025147753239425144246731563936919759762941082828639368682348489967125583588985128532813649899738197718331503075482742341168307640517723433322275956674047462452151385195532575994928593622211565226262557713381837084171754273540775896337652189136538056765290982417771831773338916768267136363230489287358986322215368692523030754778645885427584469886215641742094275625164917783255581935205968565686948251371431235383132635629535204130515699668744326088661463909561312483176055355667414234418140131787813415732989584256509918332893333523226197846663752186177915103367148376244838851930132389988611263796981040281317141380588474552215952870501898597076584181355810618159139251799045357812915798946744937130784118549375173949836023154296624351521751624241959363155585972433779051249433181825886339233641652450484635533422446499252734174230407857423382446450395061325896163521384054219116599574444142789976602952463077886198433185131339786114354020499456932185982481845821501880125143641253685541187741179456153929874112249484494932296820319154104063892693253832442550941461268343774379381225411099349096403785984333894582913341184589471684385540315069255067916620939641325215706331697892798171377229919077348946833431207243811496459825523876182833928855606568187035859589395174569498414321568121792470102641336320906840562441855244397385415574888994941191738715703573422943332046612889425775568476416463691362133053385040731169137831426487382722956199569933603222839768272778285183515039407137667479949427237225348546267415414841615755169546763712389875307455637792325053532817475677201229898779775868458121278533406557143119816959109297846927705059231397431990181192975951874218159939425873892620513945931259188179745820149788845433673540144752348546467032572396241013746647345996417990844662116926159637446096228654441793507075731737342074623849976181852260103269592771853759771161882224882846183369133315544724956012483929969347826453412276981968381181842395929187674647172825746519372675906192682598749019916444641055483155525491371485206459212553305748324052999136319055939528947235822829384587971740947621583075399581927966197397977734109195244736572276479591612034704718718118766324854130903416945285542892744633824073997070126176553924239056833274371114809741192486876490914840554925297385766343135941112717167369265383724795391896326968871020142970649581345567441578343620457684854080631617572827194475983645437560607647365456411190281341185291436350575093685560989825306936643536985230356783598388476571284696164088268583961542564327636690502175998137329912856938894574559993206398862763632435369252185817824989765749625441326460668222488919158754106181616814549090139166807358652761644760654887679079283844341128589892399444907642245177256964808229648561382968731329319974521485318883997559902685217172459924721975314869699735407927661013877046405082759987643736118014415210852025948018757493951010884937592225876051396742632829859661787424608997843220847855474431102281792640973384946348566699775777839167565379257759751876498117892132246488229785711921866574664554558370107422838155143776894544996719927855968660662284153268611140332530114587519253518061501760646555235332393359477765562795299872708682155675832395636262977871553133672799103237334375533312656183362742187923245074679266216264179730297649511447117317919839912498638629771328697485123735846445842732157925703059226275605191282971802086624478106868697364498690113014473615746239296915509727329198469753301851718684386830644270952534371857274093615286112994811829894358598181845898113323186988743928173056196598745948746845783236623890873097313357656927668765134731665879527271506225871089185144493790845393287045292987283022942388599779329757805244739875102959834099134754574277304243417590111656487889633792286786674266133829136856855339721375251259511594655377746842136886455897765972653719341983987647773979925536575955504923318942459529612838621197792359398119632970456541586275326374705880428188158255262995398023142072367493747251881120567615795598291958568611577491586063324715209823649238993986986538966185585032932059419848409843397050437821109781518437324326456841122447709760397273194788245632476970747210175019886327567933622935812138538089463715696795185025209114869023185492756479999077635864844639683818342580835481703428238050606565246197363540169464746515204199513353795419973313454893343027261642369154364833445399551369778060288236644755483180495686276525276438354082632831346144309871949262242776167447142360983112167583468316392388834938335015567080472725449671912419834197898471519758241355556753185037917268755461904576451175979295527747547074251151459897685825631394153717795690238017862173753847245489406360186585101725424745975784489675776497969763842392662015808896933967584198633147248165636318846317319793469040235813268084792637581527572077693291935835837612706035359387739692202758395935855159891290994435993477984464269367178876901726724836942520563757473834246552717285453060163492927964182535979129161099157081299673739467761626844384216284453616811456555748294530311856661418683545533439324051683433312725507059785216331539693425298314656510706978919035891992937682318377388431741191109970952221518867227678331930669385217758812662553617565467189311595850225437914468122242381599893761378962826933449973121324858125984644353387194246749727636044914792746594521460935163634687477663274978187560461058867037161221787596704678571519213624237440387853974764135478735651325644365434533751858598727157843857196582848349563514135836449084178498764061412261967948576218128442886326247230107876305629959337569435476438749278182188835190506281974714998091545099524519586521166361756390434125454947621359246189206462789182775080546793389417868469801883935895102723801370678831951426126520426465669430653218935071579224209515281548998655638146247857265318709334826321764678787776634373195328979699472677978683349581553717584754183256762610207917513814586479882114461220409586191354588826199947645043648321664999565218137278282014691070978941955415982792458546368587538080666074658915541225353492147789786388172067233713935487666688239132316728603072491915124096544525921625129365892375927113938476401874708960224919411025651565773510353212632636366844608053857579799619164735971295834791827666761968783830543440942115532457712870821555455469852229267649114668668243572376438694844294233562219764484274885720937110589043121977256077209071486367767819142758363459701452439740659620824261493279271758532125139799142177501564524994974696823827444796433012198585274126451551526676127093747218857018682051323580966824989533281459536168241623765978944264959044813481711777644094991185239657761372689223554825268356126875359418364692245638287149565916452415496244665952949326707416882025521597628027718327532066241652858184126479365070117213972969949544898590647231855491199216203047868182378653155342675040736012183447696480485685289945634883762486744892182283217661739447674290782487769064661295757622962622571987628938653913523175927773384892749241645879681958486264932576835433659689511640851245705928119068849333977139949645448453229010488049571471191863739598346829661095903960293945258711303496133782213615825344783956334232153466923273117324272359422979111177951331605362643942174280465974334587987335502935815859824482417629505292622943324844133997741493191143807582127647337627791194822892261758252888734068478351285890879752219931927734228762125625774089214758616114363288534276997747346067482478646330824386561322527939627298829413512045502980567716135472189858429280578719703868575754552915138631869114685953569955617782407243585196389766312294785334279965796542769481109576667687401497898497803212573245813387311469818570563378376990418460394589974569687381695632885479843154307080503413637021407421399637362882684514728514177692653997313693627622125728751124367559614012136912556263247387837779441727138749429599101256661466118345323758738719174898978814425426903429585525717967903487329043712232387653151738448493726873319538111454517053678395186498698612468426769164288248964586241519505836754259335759582419769422637238928195976483724920295554333024215454176549289727378877868688139951475693685290584812362936444018913646367187316214805494233016887314121038381947421085895383524178759420437542274371204296597072667687243293385199199221212913766854482773619965746586682324523112396331501625316765502982937372157394758424604865767654249669371834564041496379956411394952767294442583497626681732606641517063841950555347281026581783104744312085955546252373164058117664985989397861998750964194797471173495596872386339809465847164971495485871281264508857621124251235225984805788936931213829908876974572216082103837753232439641507326118155128885386393818050161028347529609733218895581764155053846880476282555687578345635845321269685960285258507043631713438375369144882093499888447818292781648141718974801982454625812871843157647233849672813283902637157051611758281332551892175014551330536328976226972711246249491869901720182224703552978271481058373747243081973728462043319545746410471620676163205992831379731858754945866019344697202283387876196185961179991323414377994389215210179319437839642396348617129975892038111468906262136141206743479593321917842555817089348289603668964323964157745934526844247482727656973552707173458531313984171059478216832977825131947846727233432435613142542155476026665061557555496544133055197336493927342148935861286474554285618511519684565554544732629075104299312392259315866493879234862134815623709316183555906050212040195751681075536676958187501363297220569887401416609122818544518745317817568328304724485536752884114773383060917847868261827728582041296951422766375670503162144276286273371320506997121849559514146465791324746733606841375888242918296537628952762510609954433485745223289045842616213694404276147314519157839959918795993114721993711957194955768957833114748216609032634396491877673726648224427456975648852154532116566950615834351758148430857197552664899953611733434752296178482774512329663374931584844222597152889851844971446787759169656760695713933621112842525443843027617135862210338056603022607641325287429934516969696043896143854599617031657061493097319435421745246732521243683043912867939929154419416091291932818948857057342793259754331389168230862689782422267152392090265450507217383526561860849855783859828349118546723368167640511998965278756481167160592874521732219342675157271751345247981856626870605576295753396998484959891166109261868538845620652861261939356074596593973459672924933140291766978412983761402995347399335689824153214784954245161572676720413286285417565315363847103595411833264338449973777780198316462796355062357211579023788734824793529228815281218351437911805124549186591572354496801057559560476349543636935889537957546631539223844449886095415364866021733473585593314069536299881598741582778411394946578767969465617874165175403165422212259877694079782036383583253173108874261763409863443490611846277925514886429764598350267516356673136850143815592225546143142281822919918415114244924315785586495781276348154130493082313834786228789759612060872894162191147921844841224382289569756247416476974451417757498470747678801037985880505695849841529846919181128419948862989997629378796695437516482269821829839276363185579585293271864674155862782174459867134181706768315114887340259744468855502485925759594821492675254067742510109592949027446056373377682078776253745623454078845042136979219629376129229231898568951237291469769926345943151468149632321768131896884015273448876943934159982395489554782935392746861931179093829288917025773336758579277259968689288395182215717419617759234023239235947298187483662919543894811294959067855042413513139063854338313337206445777674603033272794534585653451529259773126702792832988805433962830261362487473117098828761655972577746326762723293861994583656857860338033813768329877348979418486974471332844626812927940828815973499467985851987922245939049685463779829117023696521301432833841225816177324121983947824455734965092286661351760709840117091381548205497156291472259472033916459755383971248466980445854618383869570416340878885184690744811484973203830675238964137264491367897401815597235644631432560438480878563871967879720825881759944692163827829144720847487584597152922463216166983691473112713396685523514401143547354339316398579194341516041264041913218376497987774297876204444306353611456108440957412646165787821254085263824427684823357199899302160143386483028908048252236737078984141485569619969286519284742406925522750426425562682291129806575982578605677294716266045115177975721164967772586396612239784163391455451938360828114655197285097474087425682441652777736299213336270761363678644133167743469815668388845373859744969745867385981328916682312904786977879934552768737312295244231956195517275504867824572656287761295655742497796685929643357102136309055571712554584867992542955914264298544464714969045418763826766695547333716703470387353999139382926666784826417108123705761563277305411179329309489322870894729588995751838387870557685403060958814682489442525292245964049833139803850384120737863943697623880225582189638689915287234281522256549395213763064661355646149178342414476425672355193602999825954463543487061973461812528466649955158414551648490616541533336315483271483459327115818832831453286504234157567215575113422169131244467223985475267487394131954508690164792932827196721466164878261767267356562948814187769635290635923613569291698858395219052169310683040304271206695851192362221933433252593643350306489991414174532824736359287527527778633401152356414693572895912557073975016971354616362507933178015304927121756218520995033593292109390906776147560892493515678528996355085786853616160117941537295567834888038503824857957622476975854526046694629515940763497967963255117335031459456261779949819518121671915737879814454361590862760992261526574471961906178423663946591883058463420455180792356544315339635261053318960453361975349297931762761662820452131965040228289411786645670347574724237834955458832878966531945221512308928395962872657965188661528113144915044405528855428881480162742874149633333629651278079456178887550861617227815368482335013604097162636798928313868228310126249261264487142851545846731333480338786635950293926435466569252524610224797136980404466947757779812996881658846215721892597629451584034158870868812215485725536253658906622636273192115929523349659504377496173451826811841614017744591859082448572506635409
-"#;
@@ -1,47 +1,54 @@
-// 
+// https://adventofcode.com/YEAR/day/DAY
+use crate::{parse::ParseError, solution::Solution};
 
-fn process_input(input: &str) ->  {
+pub struct Solver;
 
-}
-
-fn solve_simple(input: &str) ->  {
+impl Solution for Solver {
+    type Input = Vec<String>;
+    type Answer = i64;
 
-}
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        process_input(input)
+    }
 
-fn solve_complex(input: &str) ->  {
+    fn part_one(&self, _input: &Self::Input) -> i64 {
+        -1
+    }
 
+    fn part_two(&self, _input: &Self::Input) -> i64 {
+        -1
+    }
 }
 
-pub fn main() {}
-
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-
-//     //Puzzle tests
-//     #[test]
-//     fn solve_simple_input_1() {
-//         assert_eq!(solve_simple(INPUT_1), -1);
-//     }
-
-//     #[test]
-//     fn solve_simple_input_2() {
-//         assert_eq!(solve_simple(INPUT_2), -1);
-//     }
-
-//     #[test]
-//     fn solve_complex_input_1() {
-//         assert_eq!(solve_complex(INPUT_1), -1);
-//     }
-
-//     #[test]
-//     fn solve_complex_input_2() {
-//         assert_eq!(solve_complex(INPUT_2), -1);
-//     }
-// }
-
-const INPUT_1: &str = r#"
-"#;
+fn process_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.trim().lines().map(String::from).collect())
+}
 
-const INPUT_2: &str = r#"
-"#;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{example, puzzle};
+    use crate::solution::{solve, Part};
+
+    // Puzzle tests. The puzzle input ones fail until inputs/ has the real input, saved by hand or with
+    // `fetch`, rather than passing on the empty placeholder.
+    #[test]
+    fn solve_simple_input_1() {
+        assert_eq!(solve(&Solver, &example(YEAR, DAY, 1), Part::One), -1);
+    }
+
+    #[test]
+    fn solve_simple_input_2() {
+        assert_eq!(solve(&Solver, &puzzle(YEAR, DAY), Part::One), -1);
+    }
+
+    #[test]
+    fn solve_complex_input_1() {
+        assert_eq!(solve(&Solver, &example(YEAR, DAY, 1), Part::Two), -1);
+    }
+
+    #[test]
+    fn solve_complex_input_2() {
+        assert_eq!(solve(&Solver, &puzzle(YEAR, DAY), Part::Two), -1);
+    }
+}
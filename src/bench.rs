@@ -0,0 +1,91 @@
+// Times parsing and each part over repeated samples.
+use std::time::Duration;
+
+use crate::{parse::ParseError, registry::Entry};
+
+pub const STEPS: [&str; 3] = ["parse", "part 1", "part 2"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    // Panics on an empty slice, since there's nothing to summarize.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        Stats {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / sorted.len() as u32,
+        }
+    }
+}
+
+// Stats for parsing, part one and part two, in the order of STEPS.
+pub fn bench_entry(entry: &Entry, input: &str, samples: u32) -> Result<[Stats; 3], ParseError> {
+    let mut times: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..samples.max(1) {
+        for (step, time) in times.iter_mut().zip(entry.solution.time(input)?) {
+            step.push(time);
+        }
+    }
+    Ok(times.map(|step| Stats::from_samples(&step)))
+}
+
+// Rounds to a readable precision, e.g. 1.23ms or 415.00µs.
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn stats_odd_samples() {
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                mean: ms(3),
+            }
+        );
+    }
+
+    #[test]
+    fn stats_even_samples() {
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(9)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(4));
+    }
+
+    #[test]
+    fn bench_registered_day() {
+        let entry = registry::find(2024, 1).unwrap();
+        let stats = bench_entry(entry, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3", 3).unwrap();
+        assert!(stats.iter().all(|step| step.min <= step.median));
+    }
+
+    #[test]
+    fn format_rounds() {
+        assert_eq!(format_duration(Duration::from_micros(1234)), "1.23ms");
+    }
+}
@@ -0,0 +1,93 @@
+// Finds where a repeating simulation falls into a loop, so the state after any number of steps can be
+// read off without simulating every one of them.
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug)]
+pub struct Cycle<T> {
+    // Every state before the first repeat, where states[i] is the state after i steps.
+    states: Vec<T>,
+    // The step the loop starts at and how many steps it takes to come back round.
+    pub start: usize,
+    pub period: usize,
+}
+
+impl<T: Clone + Eq + Hash> Cycle<T> {
+    // Steps from initial until a state comes up again, so this never returns if none ever does.
+    pub fn find(initial: T, mut step: impl FnMut(&T) -> T) -> Cycle<T> {
+        let mut seen: HashMap<T, usize> = HashMap::new();
+        let mut states = Vec::new();
+        let mut state = initial;
+        loop {
+            if let Some(&start) = seen.get(&state) {
+                return Cycle {
+                    period: states.len() - start,
+                    start,
+                    states,
+                };
+            }
+            seen.insert(state.clone(), states.len());
+            let next = step(&state);
+            states.push(state);
+            state = next;
+        }
+    }
+
+    // The state after the given number of steps.
+    pub fn state_at(&self, steps: usize) -> &T {
+        if steps < self.start {
+            &self.states[steps]
+        } else {
+            &self.states[self.start + (steps - self.start) % self.period]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 5, 26, 677 % 100 = 77, ... falls into a loop after a few steps.
+    fn step(x: &u64) -> u64 {
+        (x * x + 1) % 100
+    }
+
+    fn simulate(steps: usize) -> u64 {
+        (0..steps).fold(0, |x, _| step(&x))
+    }
+
+    #[test]
+    fn finds_prefix_and_period() {
+        let cycle = Cycle::find(0, step);
+        assert_eq!(
+            *cycle.state_at(cycle.start),
+            *cycle.state_at(cycle.start + cycle.period)
+        );
+        assert_ne!(cycle.start, 0);
+        for steps in 0..cycle.start + cycle.period * 3 {
+            assert_eq!(*cycle.state_at(steps), simulate(steps));
+        }
+    }
+
+    #[test]
+    fn extrapolates_far_steps() {
+        let cycle = Cycle::find(0, step);
+        let steps = 1_000_000_000;
+        let equivalent = cycle.start + (steps - cycle.start) % cycle.period;
+        assert_eq!(*cycle.state_at(steps), simulate(equivalent));
+    }
+
+    #[test]
+    fn loop_from_the_start() {
+        let cycle = Cycle::find(0, |x| (x + 1) % 3);
+        assert_eq!((cycle.start, cycle.period), (0, 3));
+        assert_eq!(*cycle.state_at(7), 1);
+    }
+
+    #[test]
+    fn fixed_point() {
+        let cycle = Cycle::find(5, |&x: &u64| if x > 1 { x - 1 } else { x });
+        assert_eq!((cycle.start, cycle.period), (4, 1));
+        assert_eq!(*cycle.state_at(2), 3);
+        assert_eq!(*cycle.state_at(1000), 1);
+    }
+}
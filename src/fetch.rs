@@ -0,0 +1,174 @@
+// Downloads puzzle inputs that aren't in the local cache yet.
+use std::{fmt::Display, time::Duration};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+
+// Sent with every request, as the Advent of Code site asks automated tools to identify themselves.
+const USER_AGENT: &str = "github.com/metime00/advent-of-code";
+
+#[derive(Debug)]
+pub enum FetchError {
+    // The server answered, but not with an input. Usually an expired session or a locked puzzle.
+    Status(u16, String),
+    Transport(String),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Status(code, body) => {
+                write!(f, "server responded {}: {}", code, body.trim())
+            }
+            FetchError::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+// Fetches inputs over HTTP from the Advent of Code site, or any server with the same url layout.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: &str) -> HttpFetcher {
+        HttpFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    // Builds a fetcher from AOC_SESSION, pointed at AOC_URL if set. Returns None without a session.
+    pub fn from_env() -> Option<HttpFetcher> {
+        let session = std::env::var("AOC_SESSION").ok()?;
+        let base_url = std::env::var("AOC_URL").unwrap_or_else(|_| AOC_URL.to_string());
+        Some(HttpFetcher::new(&base_url, &session))
+    }
+
+    fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let response = self
+            .agent
+            .get(&self.url(year, day))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| FetchError::Transport(e.to_string())),
+            Err(ureq::Error::Status(code, response)) => Err(FetchError::Status(
+                code,
+                response.into_string().unwrap_or_default(),
+            )),
+            Err(e) => Err(FetchError::Transport(e.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    // A stand-in for the Advent of Code site. Answers each connection with the next canned
+    // response and reports the request line and cookie it received.
+    pub struct MockServer {
+        pub url: String,
+        pub requests: Receiver<(String, Option<String>)>,
+    }
+
+    impl MockServer {
+        pub fn start(responses: Vec<(u16, &'static str)>) -> MockServer {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let (sender, requests) = mpsc::channel();
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request_line = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+                    let mut cookie = None;
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        if header.trim().is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = header.split_once(':') {
+                            if name.eq_ignore_ascii_case("cookie") {
+                                cookie = Some(value.trim().to_string());
+                            }
+                        }
+                    }
+                    sender.send((request_line.trim().to_string(), cookie)).ok();
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+            MockServer { url, requests }
+        }
+    }
+
+    #[test]
+    fn fetch_sends_session_cookie() {
+        let server = MockServer::start(vec![(200, "1 2 3\n")]);
+        let fetcher = HttpFetcher::new(&server.url, "abc123\n");
+        assert_eq!(fetcher.fetch(2024, 7).unwrap(), "1 2 3\n");
+        let (request_line, cookie) = server.requests.recv().unwrap();
+        assert_eq!(request_line, "GET /2024/day/7/input HTTP/1.1");
+        assert_eq!(cookie.as_deref(), Some("session=abc123"));
+    }
+
+    #[test]
+    fn fetch_reports_bad_status() {
+        let server = MockServer::start(vec![(400, "Puzzle inputs differ by user.")]);
+        let fetcher = HttpFetcher::new(&server.url, "expired");
+        let error = fetcher.fetch(2024, 7).unwrap_err();
+        assert!(matches!(error, FetchError::Status(400, _)));
+        assert_eq!(
+            error.to_string(),
+            "server responded 400: Puzzle inputs differ by user."
+        );
+    }
+
+    #[test]
+    fn fetch_unreachable_server() {
+        // Bind and immediately drop a listener so the port is very likely closed.
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let fetcher = HttpFetcher::new(&format!("http://127.0.0.1:{}", port), "abc123");
+        assert!(matches!(
+            fetcher.fetch(2024, 7),
+            Err(FetchError::Transport(_))
+        ));
+    }
+}
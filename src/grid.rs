@@ -0,0 +1,412 @@
+// A rectangular 2D grid stored row by row in a flat Vec.
+//
+// Positions are Points, with x counting columns from the left and y counting rows from the top.
+// Lookups are signed so callers can step off the edge and get None back.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    parse::ParseError,
+    point::{Direction, Point},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Panics if the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows must all be the same length"
+        );
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    // Reads one cell per character, each of which has to be one of allowed. Surrounding whitespace
+    // on the input and on each line is ignored.
+    pub fn parse_with(
+        input: &str,
+        allowed: &str,
+        cell: impl Fn(char) -> T,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for line in input.trim().lines() {
+            let line = line.trim();
+            if let Some((i, c)) = line.char_indices().find(|&(_, c)| !allowed.contains(c)) {
+                return Err(ParseError::at(
+                    input,
+                    &line[i..i + c.len_utf8()],
+                    format!("expected one of \"{}\"", allowed),
+                ));
+            }
+            let row: Vec<T> = line.chars().map(&cell).collect();
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("expected a row of {} characters", first.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, position: Point) -> Option<usize> {
+        let x = usize::try_from(position.x)
+            .ok()
+            .filter(|&x| x < self.width)?;
+        let y = usize::try_from(position.y)
+            .ok()
+            .filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    pub fn contains(&self, position: Point) -> bool {
+        self.offset(position).is_some()
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.offset(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.offset(position).map(|i| &mut self.cells[i])
+    }
+
+    // Every in-bounds neighbor of a position, up, right, down and left.
+    #[allow(dead_code)]
+    pub fn neighbors4(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(position, &Direction::ORTHOGONAL)
+    }
+
+    // Every in-bounds neighbor of a position including diagonals, clockwise from up.
+    #[allow(dead_code)]
+    pub fn neighbors8(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(position, &Direction::ALL)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        position: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .map(move |&direction| position + direction)
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    #[allow(dead_code)]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics on a zero chunk size, so an empty-width grid has no rows to give.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    #[allow(dead_code)]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        // stepping from past the last column would wrap round into the next row.
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    // Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point::new((i % width) as isize, (i / width) as isize), cell))
+    }
+
+    // The position of the first cell, row by row, that matches.
+    pub fn position(&self, mut matches: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| matches(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Builds a grid of the given size where each cell is copied from the old position `source` gives.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (old_x, old_y) = source(x, y);
+                self.cells[old_y * self.width + old_x].clone()
+            })
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Swaps rows and columns.
+    #[allow(dead_code)]
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    #[allow(dead_code)]
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str, allowed: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, allowed, |c| c)
+    }
+}
+
+// Indexes by unsigned (x, y), panicking when out of bounds like a slice.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+// One line per row, with no separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 wide, 2 tall:
+    // abc
+    // def
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n", "abcdef").unwrap()
+    }
+
+    #[test]
+    fn parse_dimensions() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+    }
+
+    #[test]
+    fn parse_ignores_surrounding_whitespace() {
+        assert_eq!(
+            Grid::parse("\n  ab\n  cd\n", "abcd").unwrap().row(1),
+            ['c', 'd']
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_character() {
+        let error = Grid::parse("..\n.x\n", ".#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "expected one of \".#\"");
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let error = Grid::parse("...\n..\n", ".").unwrap_err();
+        assert_eq!(error.message, "expected a row of 3 characters");
+    }
+
+    #[test]
+    fn parse_with_maps_cells() {
+        let grid = Grid::parse_with("#.\n.#\n", "#.", |c| c == '#').unwrap();
+        assert_eq!(
+            grid,
+            Grid::from_rows(vec![vec![true, false], vec![false, true]])
+        );
+    }
+
+    #[test]
+    fn checked_get() {
+        let grid = letters();
+        assert_eq!(grid.get(Point::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+    }
+
+    #[test]
+    fn get_mut_writes_cell() {
+        let mut grid = letters();
+        *grid.get_mut(Point::new(0, 1)).unwrap() = 'x';
+        assert_eq!(grid.to_string(), "abc\nxef\n");
+        assert!(grid.get_mut(Point::new(0, -1)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the grid")]
+    fn index_out_of_bounds() {
+        let _ = letters()[(3, 0)];
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = letters();
+        assert_eq!(
+            grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors8(Point::new(1, 0)).collect::<Vec<_>>(),
+            [(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)].map(|(x, y)| Point::new(x, y))
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = letters();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside the grid")]
+    fn column_out_of_bounds() {
+        let _ = letters().column(3);
+    }
+
+    #[test]
+    fn position_and_iter() {
+        let grid = letters();
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.iter().nth(4), Some((Point::new(1, 1), &'e')));
+    }
+
+    #[test]
+    fn transpose() {
+        assert_eq!(letters().transpose().to_string(), "ad\nbe\ncf\n");
+    }
+
+    #[test]
+    fn rotate() {
+        assert_eq!(letters().rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(
+            letters().rotate_counterclockwise().to_string(),
+            "cf\nbe\nad\n"
+        );
+        assert_eq!(
+            letters().rotate_clockwise().rotate_counterclockwise(),
+            letters()
+        );
+    }
+
+    #[test]
+    fn map_cells() {
+        assert_eq!(
+            letters().map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+
+    #[test]
+    fn empty_grid() {
+        let grid = Grid::parse("", ".").unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.to_string(), "");
+    }
+}
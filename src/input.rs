@@ -0,0 +1,324 @@
+// Loads puzzle inputs from inputs/<year>/<day>.txt and examples from examples/<year>/<day>-<n>.txt.
+// Inputs missing from inputs/ can be downloaded and cached by an InputManager.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::fetch::{FetchError, Fetcher, HttpFetcher};
+
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+pub fn inputs_dir() -> PathBuf {
+    Path::new(ROOT).join("inputs")
+}
+
+pub fn cache_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+pub fn example_path(year: u16, day: u8, n: u8) -> PathBuf {
+    example_file(&Path::new(ROOT).join("examples"), year, day, n)
+}
+
+pub fn example_file(examples_dir: &Path, year: u16, day: u8, n: u8) -> PathBuf {
+    examples_dir
+        .join(year.to_string())
+        .join(format!("{:02}-{}.txt", day, n))
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    MissingInput(u16, u8, PathBuf),
+    MissingExample(u16, u8, u8, PathBuf),
+    Unreadable(PathBuf, io::Error),
+    Unwritable(PathBuf, io::Error),
+    Fetch(u16, u8, FetchError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::MissingInput(year, day, path) => write!(
+                f,
+                "no puzzle input for {} day {}: save https://adventofcode.com/{}/day/{}/input to {}, or set AOC_SESSION to download it",
+                year,
+                day,
+                year,
+                day,
+                path.display()
+            ),
+            InputError::MissingExample(year, day, n, path) => write!(
+                f,
+                "no example {} for {} day {}: expected it at {}",
+                n,
+                year,
+                day,
+                path.display()
+            ),
+            InputError::Unreadable(path, e) => {
+                write!(f, "couldn't read {}: {}", path.display(), e)
+            }
+            InputError::Unwritable(path, e) => {
+                write!(f, "couldn't cache input at {}: {}", path.display(), e)
+            }
+            InputError::Fetch(year, day, e) => {
+                write!(f, "couldn't download {} day {}: {}", year, day, e)
+            }
+        }
+    }
+}
+
+// Reads a file, reporting a missing file with the given error instead of a bare io::Error.
+fn read(path: PathBuf, missing: impl FnOnce(PathBuf) -> InputError) -> Result<String, InputError> {
+    match fs::read_to_string(&path) {
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(missing(path)),
+        Err(e) => Err(InputError::Unreadable(path, e)),
+    }
+}
+
+// Writes a cached input readable only by the current user, since inputs are tied to an account.
+pub fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    use std::io::Write;
+    #[cfg(unix)]
+    use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+
+    if let Some(dir) = path.parent() {
+        let mut builder = fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        builder.mode(0o700);
+        builder.create(dir)?;
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    options.open(path)?.write_all(contents.as_bytes())
+}
+
+// Resolves puzzle inputs from a local cache directory first, downloading and caching them on a miss.
+pub struct InputManager {
+    cache_dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputManager {
+    pub fn new(cache_dir: PathBuf, fetcher: Option<Box<dyn Fetcher>>) -> InputManager {
+        InputManager { cache_dir, fetcher }
+    }
+
+    // Caches into inputs/, downloading with AOC_SESSION when it's set.
+    pub fn from_env() -> InputManager {
+        let fetcher = HttpFetcher::from_env().map(|fetcher| Box::new(fetcher) as Box<dyn Fetcher>);
+        InputManager::new(inputs_dir(), fetcher)
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        cache_path(&self.cache_dir, year, day)
+    }
+
+    // An empty cached file is a placeholder left by `new`, and is treated like a missing one: downloaded
+    // over when there's a session, and reported as missing when there isn't.
+    pub fn resolve(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let cached = read(self.path(year, day), |path| {
+            InputError::MissingInput(year, day, path)
+        });
+        let missing = match &cached {
+            Err(InputError::MissingInput(..)) => true,
+            Ok(contents) => contents.is_empty(),
+            Err(_) => false,
+        };
+        match (missing, &self.fetcher) {
+            (true, Some(fetcher)) => {
+                let path = self.path(year, day);
+                let input = fetcher
+                    .fetch(year, day)
+                    .map_err(|e| InputError::Fetch(year, day, e))?;
+                write_private(&path, &input).map_err(|e| InputError::Unwritable(path, e))?;
+                Ok(input)
+            }
+            (true, None) => Err(InputError::MissingInput(year, day, self.path(year, day))),
+            (false, _) => cached,
+        }
+    }
+}
+
+pub fn load_example(year: u16, day: u8, n: u8) -> Result<String, InputError> {
+    read(example_path(year, day, n), |path| {
+        InputError::MissingExample(year, day, n, path)
+    })
+}
+
+// Test helpers that fail with the load error's message.
+#[cfg(test)]
+pub fn puzzle(year: u16, day: u8) -> String {
+    // Tests never download, so a missing input fails instead of needing a session.
+    InputManager::new(inputs_dir(), None)
+        .resolve(year, day)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+pub fn example(year: u16, day: u8, n: u8) -> String {
+    load_example(year, day, n).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::MockServer;
+    use std::{cell::Cell, rc::Rc};
+
+    // A fresh, empty cache directory for one test.
+    fn temp_cache(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{}", std::process::id(), name));
+        fs::remove_dir_all(&dir).ok();
+        dir
+    }
+
+    struct CountingFetcher(Rc<Cell<u32>>);
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, _year: u16, _day: u8) -> Result<String, FetchError> {
+            self.0.set(self.0.get() + 1);
+            Ok("fetched\n".to_string())
+        }
+    }
+
+    #[test]
+    fn input_path_pads_day() {
+        assert!(cache_path(&inputs_dir(), 2024, 5).ends_with("inputs/2024/05.txt"));
+    }
+
+    #[test]
+    fn example_path_pads_day() {
+        assert!(example_path(2024, 9, 1).ends_with("examples/2024/09-1.txt"));
+    }
+
+    #[test]
+    fn load_existing_example() {
+        assert!(example(2024, 1, 1).starts_with("3   4"));
+    }
+
+    #[test]
+    fn missing_input_message() {
+        let error = InputManager::new(inputs_dir(), None)
+            .resolve(2015, 1)
+            .unwrap_err();
+        assert!(matches!(error, InputError::MissingInput(2015, 1, _)));
+        assert!(error
+            .to_string()
+            .contains("https://adventofcode.com/2015/day/1/input"));
+    }
+
+    #[test]
+    fn missing_example_message() {
+        let error = load_example(2024, 1, 9).unwrap_err();
+        assert!(error.to_string().starts_with("no example 9 for 2024 day 1"));
+    }
+
+    #[test]
+    fn resolve_prefers_cache() {
+        let cache = temp_cache("prefers");
+        write_private(&cache_path(&cache, 2024, 7), "cached\n").unwrap();
+        let fetches = Rc::new(Cell::new(0));
+        let manager = InputManager::new(
+            cache.clone(),
+            Some(Box::new(CountingFetcher(fetches.clone()))),
+        );
+        assert_eq!(manager.resolve(2024, 7).unwrap(), "cached\n");
+        assert_eq!(fetches.get(), 0);
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn resolve_downloads_over_empty_placeholder() {
+        let cache = temp_cache("placeholder");
+        write_private(&cache_path(&cache, 2024, 7), "").unwrap();
+        let fetches = Rc::new(Cell::new(0));
+        let manager = InputManager::new(
+            cache.clone(),
+            Some(Box::new(CountingFetcher(fetches.clone()))),
+        );
+        assert_eq!(manager.resolve(2024, 7).unwrap(), "fetched\n");
+        assert_eq!(fetches.get(), 1);
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn resolve_without_fetcher_reports_empty_placeholder_missing() {
+        let cache = temp_cache("placeholder-no-fetcher");
+        write_private(&cache_path(&cache, 2024, 7), "").unwrap();
+        let manager = InputManager::new(cache.clone(), None);
+        assert!(matches!(
+            manager.resolve(2024, 7),
+            Err(InputError::MissingInput(2024, 7, _))
+        ));
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn resolve_without_fetcher_reports_missing() {
+        let cache = temp_cache("no-fetcher");
+        let manager = InputManager::new(cache, None);
+        assert!(matches!(
+            manager.resolve(2024, 7),
+            Err(InputError::MissingInput(2024, 7, _))
+        ));
+    }
+
+    #[test]
+    fn resolve_downloads_and_caches_miss() {
+        let cache = temp_cache("downloads");
+        let server = MockServer::start(vec![(200, "190: 10 19\n")]);
+        let fetcher = HttpFetcher::new(&server.url, "abc123");
+        let manager = InputManager::new(cache.clone(), Some(Box::new(fetcher)));
+        assert_eq!(manager.resolve(2024, 7).unwrap(), "190: 10 19\n");
+        let (request_line, cookie) = server.requests.recv().unwrap();
+        assert_eq!(request_line, "GET /2024/day/7/input HTTP/1.1");
+        assert_eq!(cookie.as_deref(), Some("session=abc123"));
+
+        // The mock server only answers once, so the second resolve has to come from the cache.
+        assert_eq!(manager.resolve(2024, 7).unwrap(), "190: 10 19\n");
+        assert_eq!(
+            fs::read_to_string(cache_path(&cache, 2024, 7)).unwrap(),
+            "190: 10 19\n"
+        );
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn cached_input_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let cache = temp_cache("private");
+        let manager = InputManager::new(
+            cache.clone(),
+            Some(Box::new(CountingFetcher(Rc::new(Cell::new(0))))),
+        );
+        manager.resolve(2024, 7).unwrap();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&cache_path(&cache, 2024, 7)), 0o600);
+        assert_eq!(mode(&cache.join("2024")), 0o700);
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let cache = temp_cache("failed");
+        let server = MockServer::start(vec![(404, "Not found")]);
+        let fetcher = HttpFetcher::new(&server.url, "abc123");
+        let manager = InputManager::new(cache.clone(), Some(Box::new(fetcher)));
+        assert!(matches!(
+            manager.resolve(2024, 7),
+            Err(InputError::Fetch(2024, 7, FetchError::Status(404, _)))
+        ));
+        assert!(!cache_path(&cache, 2024, 7).exists());
+    }
+}
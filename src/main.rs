@@ -1,6 +1,191 @@
+mod answers;
 mod aoc_2023;
 mod aoc_2024;
+mod bench;
+mod cycle;
+mod fetch;
+mod grid;
+mod input;
+mod parallel;
+mod parse;
+mod point;
+mod registry;
+mod runner;
+mod scaffold;
+mod search;
+mod solution;
+mod table;
 
-fn main() {
-    aoc_2024::aoc_2024_9::main();
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Parser, Subcommand};
+use runner::Source;
+use solution::Part;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How many threads to spread per-line work across (needs the parallel feature).
+    #[arg(long, global = true, value_parser = clap::value_parser!(u32).range(1..))]
+    threads: Option<u32>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every registered day with --all.
+    Run {
+        #[arg(required_unless_present = "all")]
+        year: Option<u16>,
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Only run this part (1 or 2).
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from this file instead of inputs/<year>/<day>.txt.
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Run on examples/<year>/<day>-<n>.txt instead of the puzzle input.
+        #[arg(long, conflicts_with_all = ["all", "input"])]
+        example: Option<u8>,
+        #[arg(long, conflicts_with_all = ["year", "day"])]
+        all: bool,
+    },
+    /// Explain how a day's input works out, for days that have a report.
+    Report {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from this file instead of inputs/<year>/<day>.txt.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Report on examples/<year>/<day>-<n>.txt instead of the puzzle input.
+        #[arg(long, conflicts_with = "input")]
+        example: Option<u8>,
+    },
+    /// Time parsing and each part of one day, or summarize every registered day with --all.
+    Bench {
+        #[arg(required_unless_present = "all")]
+        year: Option<u16>,
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// How many times to run each day.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        samples: u32,
+        #[arg(long, conflicts_with_all = ["year", "day"])]
+        all: bool,
+    },
+    /// Download a puzzle input into inputs/ using the AOC_SESSION cookie.
+    Fetch { year: u16, day: u8 },
+    /// Generate and register a module for a new day, with empty example and input files.
+    New {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Check every registered day against the known answers in answers.toml.
+    Verify,
+    /// List every registered day.
+    List,
+}
+
+fn to_part(part: Option<u8>) -> Option<Part> {
+    part.map(|part| if part == 1 { Part::One } else { Part::Two })
+}
+
+// Where --input and --example point a single day at.
+fn source(input: &Option<PathBuf>, example: Option<u8>) -> Source<'_> {
+    match (input, example) {
+        (Some(path), _) => Source::File(path),
+        (None, Some(n)) => Source::Example(n),
+        (None, None) => Source::Puzzle,
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(threads) = cli.threads {
+        if let Err(e) = parallel::set_threads(threads as usize) {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    match cli.command {
+        Command::Run {
+            all: true, part, ..
+        } => runner::run_all(to_part(part)),
+        Command::Run {
+            year: Some(year),
+            day: Some(day),
+            part,
+            input,
+            example,
+            ..
+        } => {
+            if let Err(e) = runner::run(year, day, to_part(part), source(&input, example)) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Run { .. } => unreachable!("clap requires a year and day without --all"),
+        Command::Report {
+            year,
+            day,
+            input,
+            example,
+        } => {
+            if let Err(e) = runner::report(year, day, source(&input, example)) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            all: true, samples, ..
+        } => runner::bench_all(samples),
+        Command::Bench {
+            year: Some(year),
+            day: Some(day),
+            samples,
+            ..
+        } => {
+            if let Err(e) = runner::bench(year, day, samples) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench { .. } => unreachable!("clap requires a year and day without --all"),
+        Command::Fetch { year, day } => {
+            if let Err(e) = runner::fetch(year, day) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::New { year, day } => {
+            match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day) {
+                Ok(created) => {
+                    for path in created {
+                        println!("wrote {}", path.display());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Verify => match runner::verify() {
+            Ok(true) => {}
+            Ok(false) => return ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::List => runner::list(),
+    }
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,79 @@
+// Independent per-item work, such as checking each line of a puzzle on its own. With the
+// `parallel` feature the items are spread across rayon's work-stealing pool, and without it they
+// run one after another. Results come back in input order either way, so answers don't change.
+
+// Applies f to every item, returning the results in the same order as items.
+#[cfg(feature = "parallel")]
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    use rayon::prelude::*;
+
+    items.par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    items.iter().map(f).collect()
+}
+
+// Sizes the pool before anything runs on it. It can only be set once per process.
+#[cfg(feature = "parallel")]
+pub fn set_threads(threads: usize) -> Result<(), String> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn set_threads(_threads: usize) -> Result<(), String> {
+    Err("--threads needs a build with the parallel feature".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_keeps_input_order() {
+        let items: Vec<u64> = (0..10_000).collect();
+        let squares = map(&items, |n| n * n);
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn map_empty() {
+        assert!(map(&[] as &[u8], |&n| n).is_empty());
+    }
+
+    // Days that go through map, run on one thread and then on several. Only meaningful with
+    // `cargo test --features parallel`, since otherwise map never leaves the calling thread.
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn days_match_on_one_thread() {
+        use crate::{input::puzzle, registry, solution::Part};
+
+        let pool = |threads| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap()
+        };
+        for day in [2, 6, 7] {
+            let entry = registry::find(2024, day).unwrap();
+            let input = puzzle(2024, day);
+            let run = || entry.solution.run(&input, &Part::BOTH).unwrap();
+            assert_eq!(
+                pool(1).install(run),
+                pool(4).install(run),
+                "2024 day {}",
+                day
+            );
+        }
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    #[test]
+    fn threads_need_feature() {
+        assert!(set_threads(4).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+// Errors for malformed puzzle input, located by line and column so they can be shown in context.
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    // 1-based, with the column counted in characters.
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+    // The whole line the offending text is on, for diagnostics.
+    pub source_line: String,
+}
+
+impl ParseError {
+    // Locates text within input. text has to be a slice of input, such as a line or token split
+    // from it, since its position is worked out from where it sits in memory.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> ParseError {
+        let offset = (text.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(
+            offset <= input.len() && text.len() <= input.len() - offset,
+            "offending text must be a slice of the input"
+        );
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: text.to_string(),
+            message: message.into(),
+            source_line: input[line_start..].lines().next().unwrap_or("").to_string(),
+        }
+    }
+
+    // Renders the offending line with the bad text underlined.
+    pub fn snippet(&self) -> String {
+        let gutter = self.line.to_string().len();
+        let underline = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |first| first.chars().count())
+            .max(1);
+        format!(
+            "{:gutter$} |\n{} | {}\n{:gutter$} | {}{}",
+            "",
+            self.line,
+            self.source_line,
+            "",
+            " ".repeat(self.column - 1),
+            "^".repeat(underline),
+            gutter = gutter
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}, found \"{}\"",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+// Parses a token as a number, reporting where it is in input if it isn't one.
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, "expected a number"))
+}
+
+// An empty slice just past the last non-whitespace character, for reporting something missing.
+pub fn end(input: &str) -> &str {
+    let trimmed = input.trim_end();
+    &trimmed[trimmed.len()..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_token() {
+        let input = "190: 10 19\n3267: 81 4O 27\n";
+        let token = input.lines().nth(1).unwrap().split(' ').nth(2).unwrap();
+        let error = ParseError::at(input, token, "expected a number");
+        assert_eq!((error.line, error.column), (2, 10));
+        assert_eq!(error.text, "4O");
+        assert_eq!(error.source_line, "3267: 81 4O 27");
+    }
+
+    #[test]
+    fn locate_counts_characters() {
+        let input = "é x";
+        let error = ParseError::at(input, &input[3..], "bad");
+        assert_eq!(error.column, 3);
+    }
+
+    #[test]
+    fn locate_empty_input() {
+        let error = ParseError::at("", "", "empty input");
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "slice of the input")]
+    fn reject_unrelated_text() {
+        ParseError::at("abc", "abc".to_string().as_str(), "bad");
+    }
+
+    #[test]
+    fn message_and_snippet() {
+        let input = "1 2\n3 x 5\n";
+        let error = number::<i32>(input, &input[6..7]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a number, found \"x\""
+        );
+        assert_eq!(error.snippet(), "  |\n2 | 3 x 5\n  |   ^");
+    }
+
+    #[test]
+    fn end_points_past_last_line() {
+        let input = "ab\ncd\n\n";
+        let error = ParseError::at(input, end(input), "expected more");
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn number_parses_valid_token() {
+        let input = "42";
+        assert_eq!(number::<u64>(input, input), Ok(42));
+    }
+}
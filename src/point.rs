@@ -0,0 +1,194 @@
+// Grid positions and the directions to step between them.
+//
+// x counts columns rightward and y counts rows downward, matching Grid, so Up is a negative y step.
+
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    #[allow(dead_code)]
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: isize) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+// One step in a direction.
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+// Listed clockwise from Up, so the discriminant counts eighth turns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    #[allow(dead_code)]
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::UpRight => Point::new(1, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(0, 1),
+            Direction::DownLeft => Point::new(-1, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    fn turn(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    // A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.turn(2)
+    }
+
+    // A quarter turn counter-clockwise.
+    #[allow(dead_code)]
+    pub fn turn_left(self) -> Direction {
+        self.turn(6)
+    }
+
+    #[allow(dead_code)]
+    pub fn reverse(self) -> Direction {
+        self.turn(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a * 3, Point::new(9, -6));
+    }
+
+    #[test]
+    fn manhattan_distance() {
+        assert_eq!(Point::new(3, -2).manhattan(Point::new(-1, 5)), 11);
+        assert_eq!(Point::new(1, 1).manhattan(Point::new(1, 1)), 0);
+    }
+
+    #[test]
+    fn step_in_direction() {
+        let mut point = Point::new(2, 2);
+        point += Direction::Up;
+        assert_eq!(point, Point::new(2, 1));
+        assert_eq!(point + Direction::DownLeft, Point::new(1, 2));
+        assert_eq!(point + Direction::Right.offset() * 3, Point::new(5, 1));
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+        assert_eq!(Direction::DownLeft.reverse(), Direction::UpRight);
+    }
+
+    #[test]
+    fn offsets_are_unit_steps() {
+        for direction in Direction::ALL {
+            let offset = direction.offset();
+            assert_eq!(offset + direction.reverse().offset(), Point::default());
+            assert!(offset.x.abs() <= 1 && offset.y.abs() <= 1 && offset != Point::default());
+        }
+        assert!(Direction::ORTHOGONAL
+            .iter()
+            .all(|d| d.offset().manhattan(Point::default()) == 1));
+        assert!(Direction::DIAGONAL
+            .iter()
+            .all(|d| d.offset().manhattan(Point::default()) == 2));
+    }
+}
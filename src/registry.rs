@@ -0,0 +1,73 @@
+// Every solved day, keyed by (year, day).
+use crate::{aoc_2023::*, aoc_2024::*, solution::DynSolution};
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn DynSolution,
+}
+
+// Registers a day module that exposes a `Solver`.
+macro_rules! entry {
+    ($year:literal, $day:literal, $module:ident) => {
+        Entry {
+            year: $year,
+            day: $day,
+            solution: &$module::Solver,
+        }
+    };
+}
+
+// Kept sorted by year, then day.
+pub const SOLUTIONS: &[Entry] = &[
+    entry!(2023, 7, aoc_2023_7),
+    entry!(2023, 14, aoc_2023_14),
+    entry!(2024, 1, aoc_2024_1),
+    entry!(2024, 2, aoc_2024_2),
+    entry!(2024, 3, aoc_2024_3),
+    entry!(2024, 4, aoc_2024_4),
+    entry!(2024, 5, aoc_2024_5),
+    entry!(2024, 6, aoc_2024_6),
+    entry!(2024, 7, aoc_2024_7),
+    entry!(2024, 9, aoc_2024_9),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    SOLUTIONS
+        .iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    #[test]
+    fn find_registered_day() {
+        let entry = find(2024, 7).unwrap();
+        assert_eq!((entry.year, entry.day), (2024, 7));
+    }
+
+    #[test]
+    fn find_unregistered_day() {
+        assert!(find(2024, 8).is_none());
+    }
+
+    #[test]
+    fn solutions_are_sorted_and_unique() {
+        assert!(SOLUTIONS
+            .windows(2)
+            .all(|pair| (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day)));
+    }
+
+    #[test]
+    fn run_registered_day() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        let entry = find(2024, 1).unwrap();
+        assert_eq!(
+            entry.solution.run(input, &Part::BOTH).unwrap(),
+            ["11", "31"]
+        );
+    }
+}
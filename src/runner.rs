@@ -0,0 +1,403 @@
+// Runs registered days from the command line.
+use std::{fmt::Display, fs, path::Path};
+
+use crate::{
+    answers::{self, Answers, AnswersError},
+    bench::{self, Stats},
+    input::{self, InputError, InputManager},
+    parse::ParseError,
+    registry::{self, Entry, SOLUTIONS},
+    solution::Part,
+    table,
+};
+
+#[derive(Debug)]
+pub enum RunError {
+    UnknownDay(u16, u8),
+    NoReport(u16, u8),
+    Input(String, std::io::Error),
+    MissingInput(InputError),
+    Answers(AnswersError),
+    Parse(u16, u8, ParseError),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::UnknownDay(year, day) => write!(f, "{} day {} is not registered", year, day),
+            RunError::NoReport(year, day) => write!(f, "{} day {} has no report", year, day),
+            RunError::Input(path, e) => write!(f, "couldn't read input {}: {}", path, e),
+            RunError::MissingInput(e) => e.fmt(f),
+            RunError::Answers(e) => e.fmt(f),
+            RunError::Parse(year, day, e) => write!(
+                f,
+                "couldn't parse input for {} day {} at {}\n{}",
+                year,
+                day,
+                e,
+                e.snippet()
+            ),
+        }
+    }
+}
+
+fn parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    }
+}
+
+// Runs the requested part of the day, or both parts when part is None.
+pub fn run_entry(entry: &Entry, part: Option<Part>, input: &str) -> Result<(), RunError> {
+    let parts = parts(part);
+    let answers = entry
+        .solution
+        .run(input, &parts)
+        .map_err(|e| RunError::Parse(entry.year, entry.day, e))?;
+    for (part, answer) in parts.iter().zip(answers) {
+        println!("{} day {} part {}: {}", entry.year, entry.day, part, answer);
+    }
+    Ok(())
+}
+
+// Where a single run reads its input from.
+pub enum Source<'a> {
+    Puzzle,
+    Example(u8),
+    File(&'a Path),
+}
+
+fn read_source(year: u16, day: u8, source: Source) -> Result<String, RunError> {
+    match source {
+        Source::Puzzle => InputManager::from_env()
+            .resolve(year, day)
+            .map_err(RunError::MissingInput),
+        Source::Example(n) => input::load_example(year, day, n).map_err(RunError::MissingInput),
+        Source::File(path) => {
+            fs::read_to_string(path).map_err(|e| RunError::Input(path.display().to_string(), e))
+        }
+    }
+}
+
+pub fn run(year: u16, day: u8, part: Option<Part>, source: Source) -> Result<(), RunError> {
+    let entry = registry::find(year, day).ok_or(RunError::UnknownDay(year, day))?;
+    let input = read_source(year, day, source)?;
+    run_entry(entry, part, &input)
+}
+
+// Prints a day's report on its input instead of the answers.
+pub fn report(year: u16, day: u8, source: Source) -> Result<(), RunError> {
+    let entry = registry::find(year, day).ok_or(RunError::UnknownDay(year, day))?;
+    let input = read_source(year, day, source)?;
+    let report = entry
+        .solution
+        .report(&input)
+        .map_err(|e| RunError::Parse(year, day, e))?
+        .ok_or(RunError::NoReport(year, day))?;
+    print!("{}", report);
+    Ok(())
+}
+
+// Runs every registered day, skipping days whose puzzle input can't be found, downloaded or parsed.
+pub fn run_all(part: Option<Part>) {
+    let inputs = InputManager::from_env();
+    for entry in SOLUTIONS {
+        let result = inputs
+            .resolve(entry.year, entry.day)
+            .map_err(RunError::MissingInput)
+            .and_then(|input| run_entry(entry, part, &input));
+        if let Err(e) = result {
+            eprintln!("skipping {} day {}: {}", entry.year, entry.day, e);
+        }
+    }
+}
+
+// Downloads a day's puzzle input into the cache without running anything.
+pub fn fetch(year: u16, day: u8) -> Result<(), RunError> {
+    let inputs = InputManager::from_env();
+    inputs.resolve(year, day).map_err(RunError::MissingInput)?;
+    println!("{}", inputs.path(year, day).display());
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Verdict {
+    Pass,
+    Fail,
+    // No known answer to compare against.
+    Missing,
+    // The puzzle input couldn't be found or downloaded, so nothing ran.
+    NoInput,
+    // The puzzle input was rejected by the parser. Counted as a failure.
+    Unparsed,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "FAIL",
+            Verdict::Missing => "missing",
+            Verdict::NoInput => "no input",
+            Verdict::Unparsed => "PARSE ERROR",
+        })
+    }
+}
+
+fn verdict(expected: Option<&str>, actual: &str) -> Verdict {
+    match expected {
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(_) => Verdict::Fail,
+        None => Verdict::Missing,
+    }
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    part: Part,
+    expected: Option<String>,
+    actual: Option<String>,
+    verdict: Verdict,
+}
+
+fn verify_entry(entry: &Entry, answers: &Answers, input: Option<&str>) -> Vec<Row> {
+    let (actuals, missing) = match input.map(|input| entry.solution.run(input, &Part::BOTH)) {
+        Some(Ok(answers)) => (answers.into_iter().map(Some).collect(), Verdict::NoInput),
+        Some(Err(e)) => {
+            eprintln!("{}", RunError::Parse(entry.year, entry.day, e));
+            (vec![None; Part::BOTH.len()], Verdict::Unparsed)
+        }
+        None => (vec![None; Part::BOTH.len()], Verdict::NoInput),
+    };
+    Part::BOTH
+        .iter()
+        .zip(actuals)
+        .map(|(&part, actual)| {
+            let expected = answers.get(entry.year, entry.day, part);
+            let verdict = match &actual {
+                Some(actual) => verdict(expected.as_deref(), actual),
+                None => missing,
+            };
+            Row {
+                year: entry.year,
+                day: entry.day,
+                part,
+                expected,
+                actual,
+                verdict,
+            }
+        })
+        .collect()
+}
+
+fn render_table(rows: &[Row]) -> String {
+    let header = ["year", "day", "part", "expected", "actual", "result"];
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.year.to_string(),
+                row.day.to_string(),
+                row.part.to_string(),
+                row.expected.clone().unwrap_or_else(|| "-".to_string()),
+                row.actual.clone().unwrap_or_else(|| "-".to_string()),
+                row.verdict.to_string(),
+            ]
+        })
+        .collect();
+    table::render(header, &cells)
+}
+
+// Checks every registered day against answers.toml and prints a table of results.
+// Returns false if any known answer didn't match.
+pub fn verify() -> Result<bool, RunError> {
+    let answers = Answers::load(&answers::answers_path()).map_err(RunError::Answers)?;
+    let inputs = InputManager::from_env();
+    let mut rows = Vec::new();
+    for entry in SOLUTIONS {
+        let input = inputs.resolve(entry.year, entry.day).ok();
+        rows.extend(verify_entry(entry, &answers, input.as_deref()));
+    }
+    print!("{}", render_table(&rows));
+    let count = |verdict: Verdict| rows.iter().filter(|row| row.verdict == verdict).count();
+    let failed = count(Verdict::Fail) + count(Verdict::Unparsed);
+    println!(
+        "\n{} passed, {} failed, {} missing, {} without input",
+        count(Verdict::Pass),
+        failed,
+        count(Verdict::Missing),
+        count(Verdict::NoInput)
+    );
+    Ok(failed == 0)
+}
+
+// Times one day on its puzzle input, reporting each step over the samples.
+pub fn bench(year: u16, day: u8, samples: u32) -> Result<(), RunError> {
+    let entry = registry::find(year, day).ok_or(RunError::UnknownDay(year, day))?;
+    let input = InputManager::from_env()
+        .resolve(year, day)
+        .map_err(RunError::MissingInput)?;
+    let stats =
+        bench::bench_entry(entry, &input, samples).map_err(|e| RunError::Parse(year, day, e))?;
+    let rows: Vec<[String; 4]> = bench::STEPS
+        .iter()
+        .zip(stats)
+        .map(|(step, stats)| {
+            [
+                step.to_string(),
+                bench::format_duration(stats.min),
+                bench::format_duration(stats.median),
+                bench::format_duration(stats.mean),
+            ]
+        })
+        .collect();
+    println!("{} day {}, {} samples", year, day, samples.max(1));
+    print!(
+        "{}",
+        table::render(["step", "min", "median", "mean"], &rows)
+    );
+    Ok(())
+}
+
+fn summary_row(entry: &Entry, stats: &[Stats; 3]) -> [String; 6] {
+    let total = stats.iter().map(|step| step.median).sum();
+    [
+        entry.year.to_string(),
+        entry.day.to_string(),
+        bench::format_duration(stats[0].median),
+        bench::format_duration(stats[1].median),
+        bench::format_duration(stats[2].median),
+        bench::format_duration(total),
+    ]
+}
+
+// Times every registered day with an input and prints the median of each step side by side.
+pub fn bench_all(samples: u32) {
+    let inputs = InputManager::from_env();
+    let mut rows = Vec::new();
+    for entry in SOLUTIONS {
+        let stats = inputs
+            .resolve(entry.year, entry.day)
+            .map_err(RunError::MissingInput)
+            .and_then(|input| {
+                bench::bench_entry(entry, &input, samples)
+                    .map_err(|e| RunError::Parse(entry.year, entry.day, e))
+            });
+        match stats {
+            Ok(stats) => rows.push(summary_row(entry, &stats)),
+            Err(e) => eprintln!("skipping {} day {}: {}", entry.year, entry.day, e),
+        }
+    }
+    println!("median of {} samples", samples.max(1));
+    print!(
+        "{}",
+        table::render(["year", "day", "parse", "part 1", "part 2", "total"], &rows)
+    );
+}
+
+pub fn list() {
+    for entry in SOLUTIONS {
+        println!("{} day {}", entry.year, entry.day);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn parts_default_to_both() {
+        assert_eq!(parts(None), [Part::One, Part::Two]);
+        assert_eq!(parts(Some(Part::Two)), [Part::Two]);
+    }
+
+    #[test]
+    fn run_unregistered_day() {
+        assert!(matches!(
+            run(2024, 8, None, Source::Puzzle),
+            Err(RunError::UnknownDay(2024, 8))
+        ));
+    }
+
+    #[test]
+    fn report_unsupported_day() {
+        assert!(matches!(
+            report(2024, 1, Source::Example(1)),
+            Err(RunError::NoReport(2024, 1))
+        ));
+    }
+
+    #[test]
+    fn run_missing_input_file() {
+        let path = Path::new("does/not/exist.txt");
+        assert!(matches!(
+            run(2024, 1, None, Source::File(path)),
+            Err(RunError::Input(_, _))
+        ));
+    }
+
+    #[test]
+    fn summary_totals_medians() {
+        let entry = registry::find(2024, 1).unwrap();
+        let step = |micros| Stats {
+            min: Duration::ZERO,
+            median: Duration::from_micros(micros),
+            mean: Duration::ZERO,
+        };
+        assert_eq!(
+            summary_row(entry, &[step(10), step(200), step(3000)]),
+            ["2024", "1", "10.00µs", "200.00µs", "3.00ms", "3.21ms"]
+        );
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(verdict(Some("11"), "11"), Verdict::Pass);
+        assert_eq!(verdict(Some("11"), "12"), Verdict::Fail);
+        assert_eq!(verdict(None, "11"), Verdict::Missing);
+    }
+
+    #[test]
+    fn verify_entry_against_answers() {
+        let entry = registry::find(2024, 1).unwrap();
+        let answers = Answers::parse("[2024.1]\npart1 = 11\n").unwrap();
+        let rows = verify_entry(
+            entry,
+            &answers,
+            Some("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"),
+        );
+        let verdicts: Vec<_> = rows.iter().map(|row| &row.verdict).collect();
+        assert_eq!(verdicts, [&Verdict::Pass, &Verdict::Missing]);
+
+        let rows = verify_entry(entry, &answers, None);
+        assert!(rows.iter().all(|row| row.verdict == Verdict::NoInput));
+    }
+
+    #[test]
+    fn table_aligns_columns() {
+        let rows = [Row {
+            year: 2024,
+            day: 7,
+            part: Part::One,
+            expected: Some("7885693428401".to_string()),
+            actual: Some("12".to_string()),
+            verdict: Verdict::Fail,
+        }];
+        assert_eq!(
+            render_table(&rows),
+            "year  day  part  expected       actual  result\n\
+             2024  7    1     7885693428401  12      FAIL\n"
+        );
+    }
+
+    #[test]
+    fn run_missing_example() {
+        assert!(matches!(
+            run(2024, 1, None, Source::Example(9)),
+            Err(RunError::MissingInput(_))
+        ));
+    }
+}
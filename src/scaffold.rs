@@ -0,0 +1,274 @@
+// Generates a new day module from aoc_template.rs and registers it.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::input;
+
+const TEMPLATE: &str = include_str!("aoc_template.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(u16, u8),
+    Unreadable(PathBuf, io::Error),
+    Unwritable(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(year, day) => {
+                write!(f, "{} day {} already exists", year, day)
+            }
+            ScaffoldError::Unreadable(path, e) => {
+                write!(f, "couldn't read {}: {}", path.display(), e)
+            }
+            ScaffoldError::Unwritable(path, e) => {
+                write!(f, "couldn't write {}: {}", path.display(), e)
+            }
+        }
+    }
+}
+
+fn render_template(year: u16, day: u8) -> String {
+    TEMPLATE
+        .replace("YEAR", &year.to_string())
+        .replace("DAY", &day.to_string())
+}
+
+// Adds a line to the contiguous block of lines starting with prefix, keeping the block sorted
+// the way rustfmt orders mod declarations.
+fn insert_sorted_line(text: &str, prefix: &str, line: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with(prefix))
+        .unwrap_or(lines.len());
+    let end = start
+        + lines[start..]
+            .iter()
+            .take_while(|l| l.starts_with(prefix))
+            .count();
+    let mut block = lines[start..end].to_vec();
+    block.push(line);
+    block.sort_unstable_by_key(|l| l.trim_end_matches(';'));
+    block.dedup();
+    let mut result: Vec<&str> = lines[..start].to_vec();
+    result.extend(block);
+    result.extend(&lines[end..]);
+    result.join("\n") + "\n"
+}
+
+// Adds the day to SOLUTIONS in year, day order and makes its year module visible to the registry.
+fn insert_entry(registry: &str, year: u16, day: u8) -> Result<String, ScaffoldError> {
+    let entry_pattern = Regex::new(r"^\s*entry!\((\d+), (\d+), \w+\),$").unwrap();
+    let key = |line: &str| {
+        entry_pattern
+            .captures(line)
+            .map(|c| (c[1].parse::<u16>().unwrap(), c[2].parse::<u8>().unwrap()))
+    };
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    if lines.iter().any(|line| key(line) == Some((year, day))) {
+        return Err(ScaffoldError::AlreadyExists(year, day));
+    }
+    // Insert before the first later entry, or after the last earlier one.
+    let position = lines
+        .iter()
+        .position(|line| key(line).is_some_and(|k| k > (year, day)))
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| key(line).is_some())
+                .map(|i| i + 1)
+        })
+        .expect("registry.rs has no entry! lines");
+    lines.insert(
+        position,
+        format!("    entry!({}, {}, aoc_{}_{}),", year, day, year, day),
+    );
+
+    let registry = lines.join("\n") + "\n";
+    let use_pattern = Regex::new(r"use crate::\{([^}]*)\};").unwrap();
+    let year_pattern = Regex::new(r"aoc_(\d+)::\*").unwrap();
+    let imports = &use_pattern
+        .captures(&registry)
+        .expect("registry.rs has no crate import")[1];
+    let mut years: Vec<u16> = year_pattern
+        .captures_iter(imports)
+        .map(|c| c[1].parse().unwrap())
+        .collect();
+    if years.contains(&year) {
+        return Ok(registry);
+    }
+    years.push(year);
+    years.sort_unstable();
+    let imports = years
+        .iter()
+        .map(|year| format!("aoc_{}::*", year))
+        .chain(std::iter::once("solution::DynSolution".to_string()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(use_pattern
+        .replace(&registry, format!("use crate::{{{}}};", imports).as_str())
+        .into_owned())
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|e| ScaffoldError::Unreadable(path.to_path_buf(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let unwritable = |e| ScaffoldError::Unwritable(path.to_path_buf(), e);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(unwritable)?;
+    }
+    fs::write(path, contents).map_err(unwritable)
+}
+
+// Creates the day module, registers it, and adds empty example and input files that don't exist
+// yet. Everything is relative to the crate root, and the created or changed files are returned.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let src = root.join("src");
+    let year_module = src.join(format!("aoc_{}.rs", year));
+    let day_module = src
+        .join(format!("aoc_{}", year))
+        .join(format!("aoc_{}_{}.rs", year, day));
+    let registry_path = src.join("registry.rs");
+    let main_path = src.join("main.rs");
+    if day_module.exists() {
+        return Err(ScaffoldError::AlreadyExists(year, day));
+    }
+
+    // Work out every edit before writing anything, so a failure leaves the tree untouched.
+    let registry = insert_entry(&read(&registry_path)?, year, day)?;
+    let mut changes = vec![(day_module, render_template(year, day))];
+    let mod_line = format!("pub mod aoc_{}_{};", year, day);
+    if year_module.exists() {
+        let modules = insert_sorted_line(&read(&year_module)?, "pub mod ", &mod_line);
+        changes.push((year_module, modules));
+    } else {
+        changes.push((year_module, mod_line + "\n"));
+        let main = insert_sorted_line(&read(&main_path)?, "mod ", &format!("mod aoc_{};", year));
+        changes.push((main_path, main));
+    }
+    changes.push((registry_path, registry));
+
+    let mut created = Vec::new();
+    for (path, contents) in changes {
+        write(&path, &contents)?;
+        created.push(path);
+    }
+    let example = input::example_file(&root.join("examples"), year, day, 1);
+    if !example.exists() {
+        write(&example, "")?;
+        created.push(example);
+    }
+    // Left empty so `run` and `fetch` download over it once AOC_SESSION is set.
+    let puzzle = input::cache_path(&root.join("inputs"), year, day);
+    if !puzzle.exists() {
+        input::write_private(&puzzle, "")
+            .map_err(|e| ScaffoldError::Unwritable(puzzle.clone(), e))?;
+        created.push(puzzle);
+    }
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "use crate::{aoc_2023::*, aoc_2024::*, solution::DynSolution};
+
+pub const SOLUTIONS: &[Entry] = &[
+    entry!(2023, 7, aoc_2023_7),
+    entry!(2024, 1, aoc_2024_1),
+    entry!(2024, 9, aoc_2024_9),
+];
+";
+
+    #[test]
+    fn template_has_no_placeholders_left() {
+        let module = render_template(2024, 10);
+        assert!(module.starts_with("// https://adventofcode.com/2024/day/10\n"));
+        assert!(module.contains("example(2024, 10, 1)"));
+        assert!(!module.contains("YEAR") && !module.contains("DAY"));
+    }
+
+    #[test]
+    fn modules_stay_in_rustfmt_order() {
+        let modules = "pub mod aoc_2024_1;\npub mod aoc_2024_2;\npub mod aoc_2024_9;\n";
+        assert_eq!(
+            insert_sorted_line(modules, "pub mod ", "pub mod aoc_2024_10;"),
+            "pub mod aoc_2024_1;\npub mod aoc_2024_10;\npub mod aoc_2024_2;\npub mod aoc_2024_9;\n"
+        );
+    }
+
+    #[test]
+    fn mod_lines_only_touch_their_block() {
+        let main = "mod aoc_2024;\nmod fetch;\n\nuse std::path::PathBuf;\n";
+        assert_eq!(
+            insert_sorted_line(main, "mod ", "mod aoc_2025;"),
+            "mod aoc_2024;\nmod aoc_2025;\nmod fetch;\n\nuse std::path::PathBuf;\n"
+        );
+    }
+
+    #[test]
+    fn entry_inserted_in_order() {
+        let registry = insert_entry(REGISTRY, 2024, 5).unwrap();
+        assert!(registry.contains(
+            "    entry!(2024, 1, aoc_2024_1),\n    entry!(2024, 5, aoc_2024_5),\n    entry!(2024, 9, aoc_2024_9),\n"
+        ));
+        assert!(
+            registry.starts_with("use crate::{aoc_2023::*, aoc_2024::*, solution::DynSolution};")
+        );
+    }
+
+    #[test]
+    fn entry_for_new_year_imports_it() {
+        let registry = insert_entry(REGISTRY, 2025, 1).unwrap();
+        assert!(registry
+            .contains("    entry!(2024, 9, aoc_2024_9),\n    entry!(2025, 1, aoc_2025_1),\n];"));
+        assert!(registry.starts_with(
+            "use crate::{aoc_2023::*, aoc_2024::*, aoc_2025::*, solution::DynSolution};"
+        ));
+    }
+
+    #[test]
+    fn entry_already_registered() {
+        assert!(matches!(
+            insert_entry(REGISTRY, 2024, 9),
+            Err(ScaffoldError::AlreadyExists(2024, 9))
+        ));
+    }
+
+    #[test]
+    fn new_day_in_new_year() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::remove_dir_all(&root).ok();
+        write(&root.join("src/main.rs"), "mod aoc_2024;\nmod fetch;\n").unwrap();
+        write(&root.join("src/registry.rs"), REGISTRY).unwrap();
+
+        let created = new_day(&root, 2025, 3).unwrap();
+        assert_eq!(created.len(), 6);
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(read("src/aoc_2025.rs"), "pub mod aoc_2025_3;\n");
+        assert_eq!(
+            read("src/main.rs"),
+            "mod aoc_2024;\nmod aoc_2025;\nmod fetch;\n"
+        );
+        assert!(read("src/aoc_2025/aoc_2025_3.rs").contains("puzzle(2025, 3)"));
+        assert!(read("src/registry.rs").contains("entry!(2025, 3, aoc_2025_3),"));
+        assert_eq!(read("examples/2025/03-1.txt"), "");
+        assert_eq!(read("inputs/2025/03.txt"), "");
+
+        assert!(matches!(
+            new_day(&root, 2025, 3),
+            Err(ScaffoldError::AlreadyExists(2025, 3))
+        ));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
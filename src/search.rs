@@ -0,0 +1,204 @@
+// Word search over a character grid, for straight words read in any of the 8 directions and for 2D
+// shapes like a cross of letters.
+use crate::{
+    grid::Grid,
+    point::{Direction, Point},
+};
+
+// Marks a cell of a shape that matches any character.
+pub const WILDCARD: char = '.';
+
+// A word found in the grid. word indexes into the words searched for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WordMatch {
+    pub word: usize,
+    pub start: Point,
+    pub direction: Direction,
+}
+
+// Every occurrence of each word, reading from any cell in any of the 8 directions. A word that reads
+// the same backwards is found once from each end, as in a printed word search.
+pub fn find_words(grid: &Grid<char>, words: &[&str]) -> Vec<WordMatch> {
+    let mut matches = Vec::new();
+    for (start, _) in grid.iter() {
+        for (word, text) in words.iter().enumerate() {
+            if text.is_empty() {
+                continue;
+            }
+            for direction in Direction::ALL {
+                if reads(grid, text, start, direction) {
+                    matches.push(WordMatch {
+                        word,
+                        start,
+                        direction,
+                    });
+                }
+            }
+        }
+    }
+    matches
+}
+
+fn reads(grid: &Grid<char>, text: &str, start: Point, direction: Direction) -> bool {
+    text.chars()
+        .enumerate()
+        .all(|(i, c)| grid.get(start + direction.offset() * i as isize) == Some(&c))
+}
+
+// A rectangular pattern of characters, where WILDCARD cells match anything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shape(Grid<char>);
+
+impl Shape {
+    // One row per line, with surrounding whitespace ignored. Panics if the rows are ragged.
+    pub fn new(pattern: &str) -> Shape {
+        Shape(Grid::from_rows(
+            pattern
+                .trim()
+                .lines()
+                .map(|line| line.trim().chars().collect())
+                .collect(),
+        ))
+    }
+
+    // The shape turned a quarter at a time, leaving out turns that look the same as an earlier one.
+    pub fn rotations(&self) -> Vec<Shape> {
+        let mut rotations = vec![self.clone()];
+        let mut turned = self.0.rotate_clockwise();
+        for _ in 0..3 {
+            if !rotations.iter().any(|shape| shape.0 == turned) {
+                rotations.push(Shape(turned.clone()));
+            }
+            turned = turned.rotate_clockwise();
+        }
+        rotations
+    }
+
+    fn fits(&self, grid: &Grid<char>, corner: Point) -> bool {
+        // Wildcards still have to land on the grid, so check the far corner first.
+        let far = Point::new(self.0.width() as isize - 1, self.0.height() as isize - 1);
+        grid.contains(corner + far)
+            && self
+                .0
+                .iter()
+                .filter(|&(_, &c)| c != WILDCARD)
+                .all(|(offset, c)| grid.get(corner + offset) == Some(c))
+    }
+}
+
+// A shape found in the grid. shape indexes into the shapes searched for and corner is where the
+// shape's top-left cell landed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShapeMatch {
+    pub shape: usize,
+    pub corner: Point,
+}
+
+// Every placement of each shape that fits entirely within the grid.
+pub fn find_shapes(grid: &Grid<char>, shapes: &[Shape]) -> Vec<ShapeMatch> {
+    grid.iter()
+        .flat_map(|(corner, _)| {
+            shapes
+                .iter()
+                .enumerate()
+                .filter(move |(_, shape)| shape.fits(grid, corner))
+                .map(move |(shape, _)| ShapeMatch { shape, corner })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(text, "ABCDEFGHIJKLMNOPQRSTUVWXYZ.").unwrap()
+    }
+
+    #[test]
+    fn words_in_every_direction() {
+        // CAT reads right, diagonally down-right and down from the corner.
+        let grid = grid(
+            "
+            CAT.
+            AA..
+            T.T.
+            ",
+        );
+        let found = find_words(&grid, &["CAT"]);
+        let directions: Vec<_> = found.iter().map(|m| (m.start, m.direction)).collect();
+        assert_eq!(
+            directions,
+            [
+                (Point::new(0, 0), Direction::Right),
+                (Point::new(0, 0), Direction::DownRight),
+                (Point::new(0, 0), Direction::Down),
+            ]
+        );
+    }
+
+    #[test]
+    fn several_words() {
+        let grid = grid("DOG\nCAT\n");
+        let found = find_words(&grid, &["CAT", "GOD", "COW"]);
+        assert_eq!(
+            found,
+            [
+                WordMatch {
+                    word: 1,
+                    start: Point::new(2, 0),
+                    direction: Direction::Left,
+                },
+                WordMatch {
+                    word: 0,
+                    start: Point::new(0, 1),
+                    direction: Direction::Right,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn palindrome_found_from_both_ends() {
+        assert_eq!(find_words(&grid("ABA\n"), &["ABA"]).len(), 2);
+    }
+
+    #[test]
+    fn empty_word_never_matches() {
+        assert!(find_words(&grid("AB\n"), &[""]).is_empty());
+    }
+
+    #[test]
+    fn shape_rotations_skip_repeats() {
+        assert_eq!(Shape::new("M.S\n.A.\nM.S").rotations().len(), 4);
+        assert_eq!(Shape::new("A.A\n.A.\nA.A").rotations().len(), 1);
+        assert_eq!(Shape::new("AB\nBA").rotations().len(), 2);
+    }
+
+    #[test]
+    fn shapes_match_with_wildcards() {
+        let grid = grid(
+            "
+            MXS
+            XAX
+            MXS
+            ",
+        );
+        let shapes = Shape::new("M.S\n.A.\nM.S").rotations();
+        assert_eq!(
+            find_shapes(&grid, &shapes),
+            [ShapeMatch {
+                shape: 0,
+                corner: Point::new(0, 0),
+            }]
+        );
+        assert!(find_shapes(&grid, &[Shape::new("S.M\n.A.\nS.M")]).is_empty());
+    }
+
+    #[test]
+    fn shapes_stay_inside_grid() {
+        let grid = grid("AB\nCD\n");
+        let found = find_shapes(&grid, &[Shape::new("..\n..")]);
+        assert_eq!(found.len(), 1);
+    }
+}
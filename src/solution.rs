@@ -0,0 +1,144 @@
+// Common interface implemented by every day module.
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::parse::ParseError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+// A day's puzzle. The input is parsed once and shared between both parts.
+pub trait Solution {
+    type Input;
+    type Answer: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(&self, input: &Self::Input) -> Self::Answer;
+    fn part_two(&self, input: &Self::Input) -> Self::Answer;
+
+    // A human readable account of how the input works out, for days that have one to give.
+    fn report(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+// Parses input and runs one part, for tests that only care about the answer.
+#[cfg(test)]
+pub fn solve<S: Solution>(solution: &S, input: &str, part: Part) -> S::Answer {
+    let input = solution.parse(input).unwrap();
+    match part {
+        Part::One => solution.part_one(&input),
+        Part::Two => solution.part_two(&input),
+    }
+}
+
+// Object safe version of Solution, so days with different input and answer types can share a registry.
+pub trait DynSolution: Sync {
+    // Parses the input once, then returns the rendered answer for each requested part.
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<String>, ParseError>;
+    // Parses the input and runs both parts once, returning how long parsing, part one and part two took.
+    fn time(&self, input: &str) -> Result<[Duration; 3], ParseError>;
+    // Parses the input and returns the day's report, if it has one.
+    fn report(&self, input: &str) -> Result<Option<String>, ParseError>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<String>, ParseError> {
+        let input = self.parse(input)?;
+        Ok(parts
+            .iter()
+            .map(|part| match part {
+                Part::One => self.part_one(&input).to_string(),
+                Part::Two => self.part_two(&input).to_string(),
+            })
+            .collect())
+    }
+
+    fn time(&self, input: &str) -> Result<[Duration; 3], ParseError> {
+        // black_box keeps the optimizer from skipping work whose result is never used.
+        let start = Instant::now();
+        let input = black_box(self.parse(black_box(input))?);
+        let parsed = Instant::now();
+        black_box(self.part_one(&input));
+        let one = Instant::now();
+        black_box(self.part_two(&input));
+        let two = Instant::now();
+        Ok([parsed - start, one - parsed, two - one])
+    }
+
+    fn report(&self, input: &str) -> Result<Option<String>, ParseError> {
+        Ok(Solution::report(self, &self.parse(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Answer = i32;
+
+        fn parse(&self, input: &str) -> Result<Vec<i32>, ParseError> {
+            input
+                .split(',')
+                .map(|x| crate::parse::number(input, x))
+                .collect()
+        }
+
+        fn part_one(&self, input: &Vec<i32>) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part_two(&self, input: &Vec<i32>) -> i32 {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn run_both_parts() {
+        assert_eq!(Sum.run("2,3,4", &Part::BOTH).unwrap(), ["9", "24"]);
+    }
+
+    #[test]
+    fn run_single_part() {
+        assert_eq!(Sum.run("2,3,4", &[Part::Two]).unwrap(), ["24"]);
+    }
+
+    #[test]
+    fn time_every_step() {
+        assert_eq!(Sum.time("2,3,4").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn no_report_by_default() {
+        assert_eq!(DynSolution::report(&Sum, "2,3,4"), Ok(None));
+    }
+
+    #[test]
+    fn run_reports_parse_error() {
+        let error = Sum.run("2,x,4", &Part::BOTH).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+}
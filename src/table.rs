@@ -0,0 +1,42 @@
+// Renders left-aligned plain text tables for command output.
+
+// Pads each column to its widest cell, with two spaces between columns.
+pub fn render<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let mut widths = header.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let mut table = String::new();
+    let lines = std::iter::once(header.map(String::from)).chain(rows.iter().cloned());
+    for line in lines {
+        let padded: Vec<String> = line
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        table.push_str(padded.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_to_widest_cell() {
+        let rows = [
+            ["2024".to_string(), "7".to_string()],
+            ["2023".to_string(), "14".to_string()],
+        ];
+        assert_eq!(render(["year", "d"], &rows), "year  d\n2024  7\n2023  14\n");
+    }
+
+    #[test]
+    fn header_only() {
+        assert_eq!(render(["year", "day"], &[]), "year  day\n");
+    }
+}